@@ -1 +1,2 @@
 pub mod coord_format;
+pub mod total_bounds;
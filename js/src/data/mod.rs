@@ -1,9 +1,13 @@
 pub mod coord;
 
+use std::sync::Arc;
+
 use arrow_array::BinaryArray;
 use arrow_buffer::Buffer;
 pub use coord::{CoordBuffer, InterleavedCoordBuffer, SeparatedCoordBuffer};
+use geoarrow::array::AsNativeArray;
 use geoarrow::datatypes::Dimension;
+use geoarrow::{ArrayBase, NativeArray};
 
 use crate::error::WasmResult;
 use crate::utils::vec_to_offsets;
@@ -94,6 +98,25 @@ impl_data! {
     pub struct RectData(pub(crate) geoarrow::array::RectArray);
 }
 
+/// An immutable array of geometries of any type in WebAssembly memory using GeoArrow's
+/// in-memory representation.
+///
+/// The concrete geometry type is determined at runtime, such as when parsing WKB.
+#[wasm_bindgen]
+pub struct GeometryData(pub(crate) Arc<dyn NativeArray>);
+
+impl From<Arc<dyn NativeArray>> for GeometryData {
+    fn from(value: Arc<dyn NativeArray>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<GeometryData> for Arc<dyn NativeArray> {
+    fn from(value: GeometryData) -> Self {
+        value.0
+    }
+}
+
 #[wasm_bindgen]
 impl PointData {
     #[wasm_bindgen(constructor)]
@@ -109,13 +132,13 @@ impl PointData {
 #[wasm_bindgen]
 impl LineStringData {
     #[wasm_bindgen(constructor)]
-    pub fn new(coords: CoordBuffer, geom_offsets: Vec<i32>) -> Self {
-        Self(geoarrow::array::LineStringArray::new(
+    pub fn new(coords: CoordBuffer, geom_offsets: Vec<i32>) -> WasmResult<LineStringData> {
+        Ok(Self(geoarrow::array::LineStringArray::try_new(
             coords.0,
-            vec_to_offsets(geom_offsets),
+            vec_to_offsets(geom_offsets)?,
             None,
             Default::default(),
-        ))
+        )?))
     }
 }
 
@@ -127,41 +150,45 @@ impl PolygonData {
         geom_offsets: Vec<i32>,
         ring_offsets: Vec<i32>,
         // validity: Option<BooleanArray>,
-    ) -> Self {
-        Self(geoarrow::array::PolygonArray::new(
+    ) -> WasmResult<PolygonData> {
+        Ok(Self(geoarrow::array::PolygonArray::try_new(
             coords.0,
-            vec_to_offsets(geom_offsets),
-            vec_to_offsets(ring_offsets),
+            vec_to_offsets(geom_offsets)?,
+            vec_to_offsets(ring_offsets)?,
             None,
             Default::default(),
-        ))
+        )?))
     }
 }
 
 #[wasm_bindgen]
 impl MultiPointData {
     #[wasm_bindgen(constructor)]
-    pub fn new(coords: CoordBuffer, geom_offsets: Vec<i32>) -> Self {
-        Self(geoarrow::array::MultiPointArray::new(
+    pub fn new(coords: CoordBuffer, geom_offsets: Vec<i32>) -> WasmResult<MultiPointData> {
+        Ok(Self(geoarrow::array::MultiPointArray::try_new(
             coords.0,
-            vec_to_offsets(geom_offsets),
+            vec_to_offsets(geom_offsets)?,
             None,
             Default::default(),
-        ))
+        )?))
     }
 }
 
 #[wasm_bindgen]
 impl MultiLineStringData {
     #[wasm_bindgen(constructor)]
-    pub fn new(coords: CoordBuffer, geom_offsets: Vec<i32>, ring_offsets: Vec<i32>) -> Self {
-        Self(geoarrow::array::MultiLineStringArray::new(
+    pub fn new(
+        coords: CoordBuffer,
+        geom_offsets: Vec<i32>,
+        ring_offsets: Vec<i32>,
+    ) -> WasmResult<MultiLineStringData> {
+        Ok(Self(geoarrow::array::MultiLineStringArray::try_new(
             coords.0,
-            vec_to_offsets(geom_offsets),
-            vec_to_offsets(ring_offsets),
+            vec_to_offsets(geom_offsets)?,
+            vec_to_offsets(ring_offsets)?,
             None,
             Default::default(),
-        ))
+        )?))
     }
 }
 
@@ -173,29 +200,29 @@ impl MultiPolygonData {
         geom_offsets: Vec<i32>,
         polygon_offsets: Vec<i32>,
         ring_offsets: Vec<i32>,
-    ) -> Self {
-        Self(geoarrow::array::MultiPolygonArray::new(
+    ) -> WasmResult<MultiPolygonData> {
+        Ok(Self(geoarrow::array::MultiPolygonArray::try_new(
             coords.0,
-            vec_to_offsets(geom_offsets),
-            vec_to_offsets(polygon_offsets),
-            vec_to_offsets(ring_offsets),
+            vec_to_offsets(geom_offsets)?,
+            vec_to_offsets(polygon_offsets)?,
+            vec_to_offsets(ring_offsets)?,
             None,
             Default::default(),
-        ))
+        )?))
     }
 }
 
 #[wasm_bindgen]
 impl WKBData {
     #[wasm_bindgen(constructor)]
-    pub fn new(values: Vec<u8>, offsets: Vec<i32>) -> Self {
+    pub fn new(values: Vec<u8>, offsets: Vec<i32>) -> WasmResult<WKBData> {
         let binary_array =
-            BinaryArray::new(vec_to_offsets(offsets), Buffer::from_vec(values), None);
+            BinaryArray::try_new(vec_to_offsets(offsets)?, Buffer::from_vec(values), None)?;
 
-        Self(geoarrow::array::WKBArray::new(
+        Ok(Self(geoarrow::array::WKBArray::new(
             binary_array,
             Default::default(),
-        ))
+        )))
     }
 
     /// Convert this WKBData into a PointArray
@@ -271,3 +298,91 @@ impl WKBData {
         Ok(arr.into())
     }
 }
+
+macro_rules! impl_into_data {
+    ($method:ident, $js_name:ident, $as_method:ident, $struct_name:ident) => {
+        #[wasm_bindgen]
+        impl GeometryData {
+            #[doc = concat!("Convert this GeometryData into a ", stringify!($struct_name), ".")]
+            ///
+            /// Throws if the array does not hold geometries of this type.
+            #[wasm_bindgen(js_name = $js_name)]
+            pub fn $method(&self) -> WasmResult<$struct_name> {
+                let arr = self
+                    .0
+                    .as_ref()
+                    .$as_method()
+                    .ok_or(JsError::new("Geometry array is not of the requested type"))?;
+                Ok(arr.clone().into())
+            }
+        }
+    };
+}
+
+#[wasm_bindgen]
+impl GeometryData {
+    /// Parse ISO WKB into a GeometryData.
+    ///
+    /// `bytes` holds the concatenated WKB geometries, and `offsets` holds the start of each
+    /// geometry in `bytes` followed by the total length. The geometry type of the output is
+    /// inferred from the data, preferring the simplest type that can hold every geometry.
+    #[wasm_bindgen(js_name = fromWKB)]
+    pub fn from_wkb(bytes: Vec<u8>, offsets: Vec<i32>) -> WasmResult<GeometryData> {
+        use geoarrow::io::wkb::FromWKB;
+
+        let wkb_array = WKBData::new(bytes, offsets)?.0;
+        let arr: Arc<dyn NativeArray> =
+            FromWKB::from_wkb(&wkb_array, Default::default(), Dimension::XY)?;
+        Ok(arr.into())
+    }
+
+    /// The number of geometries in this array.
+    #[wasm_bindgen]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl_into_data!(into_point_data, intoPointData, as_point_opt, PointData);
+impl_into_data!(
+    into_line_string_data,
+    intoLineStringData,
+    as_line_string_opt,
+    LineStringData
+);
+impl_into_data!(
+    into_polygon_data,
+    intoPolygonData,
+    as_polygon_opt,
+    PolygonData
+);
+impl_into_data!(
+    into_multi_point_data,
+    intoMultiPointData,
+    as_multi_point_opt,
+    MultiPointData
+);
+impl_into_data!(
+    into_multi_line_string_data,
+    intoMultiLineStringData,
+    as_multi_line_string_opt,
+    MultiLineStringData
+);
+impl_into_data!(
+    into_multi_polygon_data,
+    intoMultiPolygonData,
+    as_multi_polygon_opt,
+    MultiPolygonData
+);
+impl_into_data!(
+    into_mixed_geometry_data,
+    intoMixedGeometryData,
+    as_mixed_opt,
+    MixedGeometryData
+);
+impl_into_data!(
+    into_geometry_collection_data,
+    intoGeometryCollectionData,
+    as_geometry_collection_opt,
+    GeometryCollectionData
+);
@@ -1,4 +1,7 @@
 use arrow_buffer::OffsetBuffer;
+use wasm_bindgen::JsError;
+
+use crate::error::WasmResult;
 #[cfg(feature = "console_error_panic_hook")]
 use wasm_bindgen::prelude::*;
 
@@ -15,8 +18,20 @@ pub fn set_panic_hook() {
 }
 
 /// Convert vec to OffsetsBuffer
-pub fn vec_to_offsets(v: Vec<i32>) -> OffsetBuffer<i32> {
-    unsafe { OffsetBuffer::new_unchecked(v.into()) }
+///
+/// Offsets passed in from JS are untrusted, so this checks that they are non-empty, non-negative,
+/// and non-decreasing. Otherwise reading geometries from the array could index out of bounds.
+pub fn vec_to_offsets(v: Vec<i32>) -> WasmResult<OffsetBuffer<i32>> {
+    if v.is_empty() {
+        return Err(JsError::new("Offsets must have at least one value"));
+    }
+    if v[0] < 0 {
+        return Err(JsError::new("Offsets must be non-negative"));
+    }
+    if v.windows(2).any(|w| w[0] > w[1]) {
+        return Err(JsError::new("Offsets must be non-decreasing"));
+    }
+    Ok(unsafe { OffsetBuffer::new_unchecked(v.into()) })
 }
 
 // A macro to provide `println!(..)`-style syntax for `console.log` logging.
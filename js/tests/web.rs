@@ -11,3 +11,72 @@ wasm_bindgen_test_configure!(run_in_browser);
 fn pass() {
     assert_eq!(1 + 1, 2);
 }
+
+#[cfg(all(feature = "algorithm", feature = "data"))]
+#[wasm_bindgen_test]
+fn polygon_simplify_reduces_vertices() {
+    use geoarrow::array::PolygonArray;
+    use geoarrow::ArrayBase;
+    use geoarrow_wasm::data::{CoordBuffer, PolygonData};
+
+    // A square with a nearly-collinear vertex along its bottom edge
+    let coords =
+        CoordBuffer::from_interleaved(vec![0., 0., 5., 0.01, 10., 0., 10., 10., 0., 10., 0., 0.]);
+    let polygons = PolygonData::new(coords, vec![0, 1], vec![0, 6]).unwrap();
+
+    let simplified: PolygonArray = polygons.simplify(1.).into();
+    assert_eq!(simplified.len(), 1);
+    assert_eq!(simplified.buffer_lengths().coord_capacity(), 5);
+}
+
+#[cfg(all(feature = "algorithm", feature = "data"))]
+#[wasm_bindgen_test]
+fn point_total_bounds() {
+    use geoarrow_wasm::data::{CoordBuffer, PointData};
+
+    let coords = CoordBuffer::from_separated(vec![1., -3.], vec![5., 2.]);
+    let points = PointData::new(coords);
+    assert_eq!(points.total_bounds(), vec![-3., 2., 1., 5.]);
+}
+
+#[cfg(feature = "data")]
+#[wasm_bindgen_test]
+fn geometry_from_wkb() {
+    use geo::point;
+    use geoarrow::array::PointArray;
+    use geoarrow::trait_::ArrayAccessor;
+    use geoarrow_wasm::data::GeometryData;
+
+    fn point_wkb(x: f64, y: f64) -> Vec<u8> {
+        let mut buf = vec![1];
+        buf.extend_from_slice(&1u32.to_le_bytes());
+        buf.extend_from_slice(&x.to_le_bytes());
+        buf.extend_from_slice(&y.to_le_bytes());
+        buf
+    }
+
+    let mut bytes = point_wkb(1., 2.);
+    bytes.extend(point_wkb(3., 4.));
+    let arr = GeometryData::from_wkb(bytes, vec![0, 21, 42]).unwrap();
+    assert_eq!(arr.length(), 2);
+
+    let points: PointArray = arr.into_point_data().unwrap().into();
+    assert_eq!(points.value_as_geo(0), point!(x: 1., y: 2.));
+    assert_eq!(points.value_as_geo(1), point!(x: 3., y: 4.));
+}
+
+#[cfg(feature = "data")]
+#[wasm_bindgen_test]
+fn decreasing_offsets_error() {
+    use geoarrow_wasm::data::{CoordBuffer, LineStringData};
+
+    let coords = CoordBuffer::from_interleaved(vec![0., 0., 1., 1., 2., 2.]);
+    assert!(LineStringData::new(coords, vec![0, 3, 2]).is_err());
+
+    // Offsets past the end of the coordinates are rejected too
+    let coords = CoordBuffer::from_interleaved(vec![0., 0., 1., 1., 2., 2.]);
+    assert!(LineStringData::new(coords, vec![0, 4]).is_err());
+
+    let coords = CoordBuffer::from_interleaved(vec![0., 0., 1., 1., 2., 2.]);
+    assert!(LineStringData::new(coords, vec![0, 3]).is_ok());
+}
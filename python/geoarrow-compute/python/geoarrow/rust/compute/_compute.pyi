@@ -627,6 +627,8 @@ def total_bounds(
     """
     Computes the total bounds (extent) of the geometry.
 
+    Serialized (WKB) input is parsed to a GeoArrow-native array first.
+
     Args:
         input: input geometry array
 
@@ -1,12 +1,34 @@
+use std::sync::Arc;
+
 use crate::ffi::from_python::AnyNativeInput;
 use geoarrow::algorithm::native::TotalBounds;
+use geoarrow::array::{AsSerializedArray, CoordType};
+use geoarrow::datatypes::Dimension;
+use geoarrow::io::wkb::FromWKB;
+use geoarrow::NativeArray;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3_geoarrow::PyGeoArrowResult;
+use pyo3_geoarrow::{PyGeoArrowResult, PySerializedArray};
 
 #[pyfunction]
-pub fn total_bounds(input: AnyNativeInput) -> PyGeoArrowResult<(f64, f64, f64, f64)> {
-    match input {
-        AnyNativeInput::Array(arr) => Ok(arr.as_ref().total_bounds().into()),
-        AnyNativeInput::Chunked(arr) => Ok(arr.as_ref().total_bounds().into()),
+pub fn total_bounds(input: &Bound<PyAny>) -> PyGeoArrowResult<(f64, f64, f64, f64)> {
+    match input.extract::<AnyNativeInput>() {
+        Ok(AnyNativeInput::Array(arr)) => Ok(arr.as_ref().total_bounds().into()),
+        Ok(AnyNativeInput::Chunked(arr)) => Ok(arr.as_ref().total_bounds().into()),
+        Err(err) => {
+            // Fall back to parsing serialized (WKB) input
+            let Ok(serialized) = input.extract::<PySerializedArray>() else {
+                return Err(err.into());
+            };
+            let serialized = serialized.as_ref();
+            let arr: Arc<dyn NativeArray> = if let Some(wkb) = serialized.as_wkb_opt() {
+                FromWKB::from_wkb(wkb, CoordType::Interleaved, Dimension::XY)?
+            } else if let Some(wkb) = serialized.as_large_wkb_opt() {
+                FromWKB::from_wkb(wkb, CoordType::Interleaved, Dimension::XY)?
+            } else {
+                return Err(PyTypeError::new_err("Expected WKB input").into());
+            };
+            Ok(arr.as_ref().total_bounds().into())
+        }
     }
 }
@@ -536,17 +536,23 @@ def from_wkt(
     input: ArrowArrayExportable,
     *,
     coord_type: CoordType | CoordTypeT = CoordType.Interleaved,
+    dimension: Dimension | DimensionT = Dimension.XY,
+    prefer_multi: bool = False,
 ) -> NativeArray: ...
 @overload
 def from_wkt(
     input: ArrowStreamExportable,
     *,
     coord_type: CoordType | CoordTypeT = CoordType.Interleaved,
+    dimension: Dimension | DimensionT = Dimension.XY,
+    prefer_multi: bool = False,
 ) -> ChunkedNativeArray: ...
 def from_wkt(
     input: ArrowArrayExportable | ArrowStreamExportable,
     *,
     coord_type: CoordType | CoordTypeT = CoordType.Interleaved,
+    dimension: Dimension | DimensionT = Dimension.XY,
+    prefer_multi: bool = False,
 ) -> NativeArray | ChunkedNativeArray:
     """
     Parse an Arrow StringArray from WKT to its GeoArrow-native counterpart.
@@ -556,6 +562,8 @@ def from_wkt(
 
     Other args:
         coord_type: Specify the coordinate type of the generated GeoArrow data.
+        dimension: The coordinate dimension of the WKT input.
+        prefer_multi: Whether to store single-part geometries in multi-part arrays.
 
     Returns:
         A GeoArrow-native geometry array
@@ -570,10 +578,10 @@ def to_wkt(input: ArrowArrayExportable | ArrowStreamExportable) -> Array | Chunk
     Encode a geometry array to WKT.
 
     Args:
-        input: An Arrow array of string type holding WKT-formatted geometries.
+        input: A GeoArrow-native geometry array.
 
     Returns:
-        A GeoArrow-native geometry array
+        An Arrow string array holding WKT-formatted geometries.
     """
 
 def to_geopandas(input: ArrowStreamExportable) -> gpd.GeoDataFrame:
@@ -42,3 +42,7 @@ class Dimension(StrEnum):
     XYZ = auto()
     """Three dimensions, X, Y, and Z
     """
+
+    XYM = auto()
+    """Two dimensions, X and Y, with a measure value M
+    """
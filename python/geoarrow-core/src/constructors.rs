@@ -5,6 +5,7 @@ use geoarrow::array::{
     LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, NativeArrayDyn,
     PointArray, PolygonArray,
 };
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3_geoarrow::{PyCoordBuffer, PyGeoArrowResult, PyNativeArray, PyOffsetBuffer};
 
@@ -35,12 +36,13 @@ pub fn linestrings(
 ) -> PyGeoArrowResult<PyNativeArray> {
     let metadata = create_array_metadata(crs);
     // TODO: remove const generic
-    let array = LineStringArray::new(
+    let array = LineStringArray::try_new(
         coords.into_inner(),
         geom_offsets.into_inner(),
         None,
         metadata,
-    );
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
 
@@ -54,13 +56,14 @@ pub fn polygons(
 ) -> PyGeoArrowResult<PyNativeArray> {
     let metadata = create_array_metadata(crs);
     // TODO: remove const generic
-    let array = PolygonArray::new(
+    let array = PolygonArray::try_new(
         coords.into_inner(),
         geom_offsets.into_inner(),
         ring_offsets.into_inner(),
         None,
         metadata,
-    );
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
 
@@ -72,12 +75,13 @@ pub fn multipoints(
     crs: Option<CRS>,
 ) -> PyGeoArrowResult<PyNativeArray> {
     let metadata = create_array_metadata(crs);
-    let array = MultiPointArray::new(
+    let array = MultiPointArray::try_new(
         coords.into_inner(),
         geom_offsets.into_inner(),
         None,
         metadata,
-    );
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
 
@@ -90,13 +94,14 @@ pub fn multilinestrings(
     crs: Option<CRS>,
 ) -> PyGeoArrowResult<PyNativeArray> {
     let metadata = create_array_metadata(crs);
-    let array = MultiLineStringArray::new(
+    let array = MultiLineStringArray::try_new(
         coords.into_inner(),
         geom_offsets.into_inner(),
         ring_offsets.into_inner(),
         None,
         metadata,
-    );
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
 
@@ -110,13 +115,14 @@ pub fn multipolygons(
     crs: Option<CRS>,
 ) -> PyGeoArrowResult<PyNativeArray> {
     let metadata = create_array_metadata(crs);
-    let array = MultiPolygonArray::new(
+    let array = MultiPolygonArray::try_new(
         coords.into_inner(),
         geom_offsets.into_inner(),
         polygon_offsets.into_inner(),
         ring_offsets.into_inner(),
         None,
         metadata,
-    );
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
@@ -3,10 +3,9 @@ use std::sync::Arc;
 use arrow::datatypes::DataType;
 use arrow_array::cast::AsArray;
 use geoarrow::array::metadata::ArrayMetadata;
+use geoarrow::array::MixedGeometryArray;
 use geoarrow::chunked_array::{ChunkedArray, ChunkedMixedGeometryArray};
-use geoarrow::datatypes::Dimension;
 use geoarrow::io::geozero::FromWKT;
-use geoarrow::io::wkt::reader::ParseWKT;
 use geoarrow::io::wkt::ToWKT;
 use geoarrow::ArrayBase;
 use pyo3::exceptions::PyTypeError;
@@ -16,33 +15,48 @@ use pyo3_arrow::{PyArray, PyChunkedArray};
 
 use crate::ffi::from_python::AnyNativeInput;
 use crate::ffi::to_python::{chunked_native_array_to_pyobject, native_array_to_pyobject};
-use pyo3_geoarrow::{PyCoordType, PyGeoArrowResult};
+use pyo3_geoarrow::{PyCoordType, PyDimension, PyGeoArrowResult};
 
 #[pyfunction]
 #[pyo3(
-    signature = (input, *, coord_type = PyCoordType::Interleaved),
-    text_signature = "(input, *, method = 'interleaved')")
+    signature = (input, *, coord_type = PyCoordType::Interleaved, dimension = PyDimension::XY, prefer_multi = false),
+    text_signature = "(input, *, coord_type = 'interleaved', dimension = 'XY', prefer_multi = False)")
 ]
 pub fn from_wkt(
     py: Python,
     input: AnyArray,
     coord_type: PyCoordType,
+    dimension: PyDimension,
+    prefer_multi: bool,
 ) -> PyGeoArrowResult<PyObject> {
     let coord_type = coord_type.into();
+    let dim = dimension.into();
     match input {
         AnyArray::Array(arr) => {
             let (array, field) = arr.into_inner();
             let metadata = Arc::new(ArrayMetadata::try_from(field.as_ref())?);
-            let geo_array = match array.data_type() {
-                DataType::Utf8 => array.as_string::<i32>().parse_wkt(coord_type, metadata),
-                DataType::LargeUtf8 => array.as_string::<i64>().parse_wkt(coord_type, metadata),
+            let geo_array: MixedGeometryArray = match array.data_type() {
+                DataType::Utf8 => FromWKT::from_wkt(
+                    array.as_string::<i32>(),
+                    coord_type,
+                    dim,
+                    metadata,
+                    prefer_multi,
+                )?,
+                DataType::LargeUtf8 => FromWKT::from_wkt(
+                    array.as_string::<i64>(),
+                    coord_type,
+                    dim,
+                    metadata,
+                    prefer_multi,
+                )?,
                 other => {
                     return Err(
                         PyTypeError::new_err(format!("Unexpected array type {:?}", other)).into(),
                     )
                 }
             };
-            native_array_to_pyobject(py, geo_array)
+            native_array_to_pyobject(py, Arc::new(geo_array))
         }
         AnyArray::Stream(s) => {
             let chunked_arr = s.into_chunked_array()?;
@@ -57,9 +71,9 @@ pub fn from_wkt(
                     FromWKT::from_wkt(
                         &ChunkedArray::new(string_chunks),
                         coord_type,
-                        Dimension::XY,
+                        dim,
                         metadata,
-                        false,
+                        prefer_multi,
                     )?
                 }
                 DataType::LargeUtf8 => {
@@ -70,9 +84,9 @@ pub fn from_wkt(
                     FromWKT::from_wkt(
                         &ChunkedArray::new(string_chunks),
                         coord_type,
-                        Dimension::XY,
+                        dim,
                         metadata,
-                        false,
+                        prefer_multi,
                     )?
                 }
                 other => {
@@ -200,6 +200,15 @@ impl PySerializedArray {
     ) -> PyGeoArrowResult<Self> {
         PyArray::from_arrow_pycapsule(schema_capsule, array_capsule)?.try_into()
     }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &dyn SerializedArray {
+        self.0.as_ref()
+    }
+
+    pub fn into_inner(self) -> SerializedArrayDyn {
+        self.0
+    }
 }
 
 #[pymethods]
@@ -126,6 +126,7 @@ impl PyNativeType {
         match self.0.dimension() {
             Dimension::XY => Ok(coord_type.getattr(intern!(py, "XY"))?.into()),
             Dimension::XYZ => Ok(coord_type.getattr(intern!(py, "XYZ"))?.into()),
+            Dimension::XYM => Ok(coord_type.getattr(intern!(py, "XYM"))?.into()),
         }
     }
 }
@@ -7,6 +7,7 @@ use pyo3::prelude::*;
 pub enum PyDimension {
     XY,
     XYZ,
+    XYM,
 }
 
 impl<'a> FromPyObject<'a> for PyDimension {
@@ -15,6 +16,7 @@ impl<'a> FromPyObject<'a> for PyDimension {
         match s.to_lowercase().as_str() {
             "xy" => Ok(Self::XY),
             "xyz" => Ok(Self::XYZ),
+            "xym" => Ok(Self::XYM),
             _ => Err(PyValueError::new_err("Unexpected dimension")),
         }
     }
@@ -25,6 +27,7 @@ impl From<PyDimension> for Dimension {
         match value {
             PyDimension::XY => Self::XY,
             PyDimension::XYZ => Self::XYZ,
+            PyDimension::XYM => Self::XYM,
         }
     }
 }
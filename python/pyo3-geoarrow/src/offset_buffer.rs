@@ -27,7 +27,20 @@ impl<'py> FromPyObject<'py> for PyOffsetBuffer {
             )));
         }
         let offsets = cast(ob.as_ref(), &DataType::Int32).map_err(PyGeoArrowError::from)?;
-        let offsets = offsets.as_ref().as_primitive::<Int32Type>();
-        Ok(Self(OffsetBuffer::new(offsets.values().clone())))
+        let offsets = offsets.as_ref().as_primitive::<Int32Type>().values();
+
+        // OffsetBuffer::new panics on invalid offsets, so validate them up front.
+        if offsets.is_empty() {
+            return Err(PyValueError::new_err(
+                "Offsets must have at least one value",
+            ));
+        }
+        if offsets[0] < 0 {
+            return Err(PyValueError::new_err("Offsets must be non-negative"));
+        }
+        if offsets.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PyValueError::new_err("Offsets must be non-decreasing"));
+        }
+        Ok(Self(OffsetBuffer::new(offsets.clone())))
     }
 }
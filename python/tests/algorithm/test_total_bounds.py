@@ -0,0 +1,16 @@
+import geoarrow.rust.compute as grc
+import geoarrow.rust.core as gars
+import numpy as np
+import shapely
+
+
+def test_total_bounds():
+    coords = np.array([[1, 5], [-3, 2], [0, 7]], dtype=np.float64)
+    points = gars.points(coords)
+    assert grc.total_bounds(points) == (-3, 2, 1, 7)
+
+
+def test_total_bounds_wkb():
+    points = shapely.points([1, -3, 0], [5, 2, 7])
+    wkb_arr = gars.to_wkb(gars.from_shapely(points))
+    assert grc.total_bounds(wkb_arr) == (-3, 2, 1, 7)
@@ -54,3 +54,17 @@ def test_linestrings():
     assert len(geom_arr[2]) == 4
 
     assert np.allclose(coords, geom_arr.values.values.to_numpy().reshape(-1, 2))
+
+
+def test_linestrings_decreasing_offsets():
+    coords = np.random.rand(10, 2)
+    geom_offsets = np.array([0, 6, 2, 10], dtype=np.int32)
+    with pytest.raises(ValueError, match="non-decreasing"):
+        linestrings(coords, geom_offsets)
+
+
+def test_linestrings_offsets_out_of_bounds():
+    coords = np.random.rand(10, 2)
+    geom_offsets = np.array([0, 2, 6, 12], dtype=np.int32)
+    with pytest.raises(ValueError):
+        linestrings(coords, geom_offsets)
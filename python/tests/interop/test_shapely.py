@@ -24,6 +24,20 @@ def test_from_shapely():
     # assert ga_arr == ga_arr_back
 
 
+def test_shapely_round_trip_coords():
+    polygons = shapely.polygons(
+        [
+            [[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]],
+            [[10, 10], [12.5, 10], [12.5, 11.25], [10, 10]],
+        ]
+    )
+    ga_arr = from_shapely(polygons)
+    shapely_rt = to_shapely(ga_arr)
+    np.testing.assert_array_equal(
+        shapely.get_coordinates(polygons), shapely.get_coordinates(shapely_rt)
+    )
+
+
 def test_from_shapely_crs():
     points = shapely.points([1, 2, 3], [4, 5, 6])
     crs = CRS.from_epsg(4326)
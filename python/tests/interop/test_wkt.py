@@ -1,6 +1,7 @@
 import pyarrow as pa
 import shapely
-from geoarrow.rust.core import from_wkt, to_shapely
+from geoarrow.rust.core import from_wkt, to_shapely, to_wkt
+from geoarrow.rust.core.enums import CoordType
 from shapely.testing import assert_geometries_equal
 
 
@@ -25,3 +26,24 @@ def test_from_wkt_chunked():
     shapely_arr = shapely.from_wkt(s1 + s2)
     geo_arr = from_wkt(ca)
     assert_geometries_equal(shapely_arr, to_shapely(geo_arr))
+
+
+def test_to_wkt_round_trip():
+    geo_arr = from_wkt(pa.array(["POINT (3 2)", "POINT (0.5 -1)"]))
+    wkt_arr = pa.array(to_wkt(geo_arr))
+    assert pa.types.is_string(wkt_arr.type)
+
+    round_tripped = from_wkt(wkt_arr)
+    assert_geometries_equal(to_shapely(geo_arr), to_shapely(round_tripped))
+
+
+def test_from_wkt_xyz():
+    geo_arr = from_wkt(pa.array(["POINT Z (1 2 3)"]), dimension="XYZ")
+    shapely_arr = to_shapely(geo_arr)
+    assert shapely.has_z(shapely_arr[0])
+    assert_geometries_equal(shapely_arr, shapely.from_wkt(["POINT Z (1 2 3)"]))
+
+
+def test_from_wkt_separated():
+    geo_arr = from_wkt(pa.array(["POINT (3 2)"]), coord_type="separated")
+    assert geo_arr.type.coord_type == CoordType.Separated
@@ -1,8 +1,10 @@
 use arrow_array::iterator::ArrayIter;
 use arrow_array::types::ArrowPrimitiveType;
-use arrow_array::PrimitiveArray;
+use arrow_array::{Array, PrimitiveArray};
 use arrow_buffer::ArrowNativeType;
 
+use crate::error::{GeoArrowError, Result};
+
 /// An enum over primitive types defined by [`ArrowPrimitiveType`]. These include u8, i32,
 /// f64, etc.
 ///
@@ -17,6 +19,38 @@ where
     Array(PrimitiveArray<T>),
 }
 
+impl<T> BroadcastablePrimitive<T>
+where
+    T: ArrowPrimitiveType,
+{
+    /// Check that this can be broadcast against an array of length `len`.
+    ///
+    /// A `Scalar` can be broadcast against any length, while an `Array` must have exactly `len`
+    /// values. Operations zip their input with this value, so without this check a short `Array`
+    /// would silently truncate the output.
+    pub fn check_len(&self, len: usize) -> Result<()> {
+        match self {
+            BroadcastablePrimitive::Scalar(_) => Ok(()),
+            BroadcastablePrimitive::Array(arr) if arr.len() == len => Ok(()),
+            BroadcastablePrimitive::Array(arr) => Err(GeoArrowError::General(format!(
+                "Arrays must have the same length, got {} and {}",
+                len,
+                arr.len()
+            ))),
+        }
+    }
+
+    /// The value at row `i`, or `None` if that row is null.
+    ///
+    /// A `Scalar` has the same value at every row.
+    pub fn value(&self, i: usize) -> Option<T::Native> {
+        match self {
+            BroadcastablePrimitive::Scalar(val) => Some(*val),
+            BroadcastablePrimitive::Array(arr) => arr.is_valid(i).then(|| arr.value(i)),
+        }
+    }
+}
+
 pub enum BroadcastIter<'a, T: ArrowPrimitiveType> {
     Scalar(T::Native),
     Array(ArrayIter<&'a PrimitiveArray<T>>),
@@ -61,6 +95,7 @@ impl<N: ArrowNativeType, P: ArrowPrimitiveType<Native = N>> From<N> for Broadcas
 mod tests {
     use crate::algorithm::broadcasting::BroadcastablePrimitive;
     use arrow_array::types::{Float64Type, UInt32Type};
+    use arrow_array::Float64Array;
 
     #[test]
     fn from_numeric() {
@@ -70,4 +105,14 @@ mod tests {
         let scalar: BroadcastablePrimitive<Float64Type> = 1.0f64.into();
         assert_eq!(scalar.into_iter().next(), Some(Some(1.0f64)));
     }
+
+    #[test]
+    fn check_len() {
+        let scalar: BroadcastablePrimitive<Float64Type> = 1.0f64.into();
+        assert!(scalar.check_len(3).is_ok());
+
+        let array = BroadcastablePrimitive::Array(Float64Array::from(vec![1., 2., 3.]));
+        assert!(array.check_len(3).is_ok());
+        assert!(array.check_len(4).is_err());
+    }
 }
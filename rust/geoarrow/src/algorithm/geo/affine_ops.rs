@@ -202,6 +202,44 @@ impl AffineOps<&AffineTransform> for &dyn ChunkedNativeArray {
     }
 }
 
+// ┌──────────────────────────────────────┐
+// │ Implementations for RHS coefficients │
+// └──────────────────────────────────────┘
+
+/// Implementation that applies a transform given as `[a, b, d, e, xoff, yoff]` coefficients.
+///
+/// See [`affine_from_coefficients`] for the coefficient order.
+macro_rules! coefficients_impl {
+    ($type:ty, $output:ty) => {
+        impl AffineOps<[f64; 6]> for $type {
+            type Output = $output;
+
+            fn affine_transform(&self, transform: [f64; 6]) -> Self::Output {
+                self.affine_transform(&affine_from_coefficients(transform))
+            }
+        }
+    };
+}
+
+coefficients_impl!(PointArray, Self);
+coefficients_impl!(LineStringArray, Self);
+coefficients_impl!(PolygonArray, Self);
+coefficients_impl!(MultiPointArray, Self);
+coefficients_impl!(MultiLineStringArray, Self);
+coefficients_impl!(MultiPolygonArray, Self);
+coefficients_impl!(MixedGeometryArray, Self);
+coefficients_impl!(GeometryCollectionArray, Self);
+coefficients_impl!(&dyn NativeArray, Result<Arc<dyn NativeArray>>);
+coefficients_impl!(ChunkedPointArray, Self);
+coefficients_impl!(ChunkedLineStringArray, Self);
+coefficients_impl!(ChunkedPolygonArray, Self);
+coefficients_impl!(ChunkedMultiPointArray, Self);
+coefficients_impl!(ChunkedMultiLineStringArray, Self);
+coefficients_impl!(ChunkedMultiPolygonArray, Self);
+coefficients_impl!(ChunkedMixedGeometryArray, Self);
+coefficients_impl!(ChunkedGeometryCollectionArray, Self);
+coefficients_impl!(&dyn ChunkedNativeArray, Result<Arc<dyn ChunkedNativeArray>>);
+
 // ┌────────────────────────────────┐
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
@@ -296,3 +334,236 @@ impl AffineOps<&[AffineTransform]> for &dyn NativeArray {
         Ok(result)
     }
 }
+
+// ┌──────────────────────────────────────────┐
+// │ Fitting a transform from control points │
+// └──────────────────────────────────────────┘
+
+/// Fit an [`AffineTransform`] mapping `src` onto `dst` from matched ground control points.
+///
+/// The six parameters of the transform are found by ordinary least squares, so with more than
+/// three control points the result minimizes the squared error between the transformed `src`
+/// points and the `dst` points. Rows where either point is null are ignored.
+///
+/// The resulting transform can be passed to [`AffineOps::affine_transform`].
+///
+/// # Errors
+///
+/// - If `src` and `dst` have different lengths.
+/// - If there are fewer than three valid point pairs, or if the `src` points are collinear.
+pub fn affine_from_gcps(src: &PointArray, dst: &PointArray) -> Result<AffineTransform> {
+    if src.len() != dst.len() {
+        return Err(GeoArrowError::General(format!(
+            "src and dst must have the same length, got {} and {}",
+            src.len(),
+            dst.len()
+        )));
+    }
+
+    let pairs = src
+        .iter_geo()
+        .zip(dst.iter_geo())
+        .filter_map(|(src_point, dst_point)| Some((src_point?, dst_point?)))
+        .collect::<Vec<_>>();
+    if pairs.len() < 3 {
+        return Err(GeoArrowError::General(format!(
+            "At least 3 control points are required, got {}",
+            pairs.len()
+        )));
+    }
+
+    // Center both point sets on their means. This decouples the offsets from the linear part of
+    // the transform and improves the conditioning of the normal equations.
+    let n = pairs.len() as f64;
+    let (mut src_mean_x, mut src_mean_y, mut dst_mean_x, mut dst_mean_y) = (0., 0., 0., 0.);
+    for (src_point, dst_point) in pairs.iter() {
+        src_mean_x += src_point.x() / n;
+        src_mean_y += src_point.y() / n;
+        dst_mean_x += dst_point.x() / n;
+        dst_mean_y += dst_point.y() / n;
+    }
+
+    let (mut sxx, mut sxy, mut syy) = (0., 0., 0.);
+    let (mut sx_u, mut sy_u, mut sx_v, mut sy_v) = (0., 0., 0., 0.);
+    for (src_point, dst_point) in pairs.iter() {
+        let x = src_point.x() - src_mean_x;
+        let y = src_point.y() - src_mean_y;
+        let u = dst_point.x() - dst_mean_x;
+        let v = dst_point.y() - dst_mean_y;
+        sxx += x * x;
+        sxy += x * y;
+        syy += y * y;
+        sx_u += x * u;
+        sy_u += y * u;
+        sx_v += x * v;
+        sy_v += y * v;
+    }
+
+    let det = sxx * syy - sxy * sxy;
+    if det.abs() <= f64::EPSILON * sxx * syy {
+        return Err(GeoArrowError::General(
+            "Control points are collinear; cannot fit an affine transform".to_string(),
+        ));
+    }
+
+    let a = (sx_u * syy - sy_u * sxy) / det;
+    let b = (sy_u * sxx - sx_u * sxy) / det;
+    let d = (sx_v * syy - sy_v * sxy) / det;
+    let e = (sy_v * sxx - sx_v * sxy) / det;
+    let xoff = dst_mean_x - a * src_mean_x - b * src_mean_y;
+    let yoff = dst_mean_y - d * src_mean_x - e * src_mean_y;
+
+    Ok(AffineTransform::new(a, b, xoff, d, e, yoff))
+}
+
+// ┌─────────────────────────────────────┐
+// │ Converting to and from coefficients │
+// └─────────────────────────────────────┘
+
+/// Create an [`AffineTransform`] from its six coefficients.
+///
+/// The coefficients are given in the order `[a, b, d, e, xoff, yoff]` used by Shapely and
+/// GeoPandas, so that each coordinate is transformed as:
+///
+/// ```ignore
+/// x' = a * x + b * y + xoff
+/// y' = d * x + e * y + yoff
+/// ```
+///
+/// Note that this differs from the argument order of [`AffineTransform::new`].
+pub fn affine_from_coefficients(coeffs: [f64; 6]) -> AffineTransform {
+    let [a, b, d, e, xoff, yoff] = coeffs;
+    AffineTransform::new(a, b, xoff, d, e, yoff)
+}
+
+/// Extract the six coefficients of an [`AffineTransform`] in the order
+/// `[a, b, d, e, xoff, yoff]`.
+///
+/// This is the inverse of [`affine_from_coefficients`].
+pub fn affine_to_coefficients(t: &AffineTransform) -> [f64; 6] {
+    // geo does not expose the matrix entries, so recover them by applying the transform. The
+    // offsets are removed before probing the linear part so that no rounding is introduced.
+    let offset = t.apply(geo::coord! { x: 0., y: 0. });
+    let linear = AffineTransform::translate(-offset.x, -offset.y).compose(t);
+    let x_axis = linear.apply(geo::coord! { x: 1., y: 0. });
+    let y_axis = linear.apply(geo::coord! { x: 0., y: 1. });
+    [x_axis.x, y_axis.x, x_axis.y, y_axis.y, offset.x, offset.y]
+}
+
+/// Compute the inverse of an [`AffineTransform`].
+///
+/// Applying the inverse after `t` maps every coordinate back to where it started, which is useful
+/// for converting between e.g. pixel and world coordinates in both directions.
+///
+/// # Errors
+///
+/// - If `t` is singular, i.e. it collapses the plane onto a line or a point.
+pub fn affine_inverse(t: &AffineTransform) -> Result<AffineTransform> {
+    t.inverse()
+        .filter(|inverse| {
+            affine_to_coefficients(inverse)
+                .iter()
+                .all(|c| c.is_finite())
+        })
+        .ok_or_else(|| {
+            GeoArrowError::General(
+                "Affine transform is singular and cannot be inverted".to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{coord, point};
+
+    fn point_array(points: &[geo::Point]) -> PointArray {
+        PointBuilder::from_points(
+            points.iter(),
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+        )
+        .finish()
+    }
+
+    #[test]
+    fn recover_translate_and_scale() {
+        let src = [
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 3., y: 7.),
+        ];
+        let expected = AffineTransform::scale(2., 3., coord! { x: 0., y: 0. }).translated(10., -5.);
+        let dst = src.map(|p| geo::Point(expected.apply(p.0)));
+
+        let transform = affine_from_gcps(&point_array(&src), &point_array(&dst)).unwrap();
+        let transformed = transform.apply(coord! { x: 4., y: 5. });
+        assert!((transformed.x - 18.).abs() < 1e-9);
+        assert!((transformed.y - 10.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn collinear_control_points() {
+        let src = [
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 2., y: 2.),
+        ];
+        let arr = point_array(&src);
+        assert!(affine_from_gcps(&arr, &arr).is_err());
+
+        let too_few = point_array(&src[..2]);
+        assert!(affine_from_gcps(&too_few, &too_few).is_err());
+    }
+
+    #[test]
+    fn coefficients_round_trip() {
+        let coeffs = [1.5, -0.25, 0.75, 2.0, 100.125, -42.5];
+        let transform = affine_from_coefficients(coeffs);
+        assert_eq!(affine_to_coefficients(&transform), coeffs);
+
+        let transformed = transform.apply(coord! { x: 2., y: 4. });
+        assert_eq!(transformed.x, 1.5 * 2. - 0.25 * 4. + 100.125);
+        assert_eq!(transformed.y, 0.75 * 2. + 2.0 * 4. - 42.5);
+
+        let transform = AffineTransform::rotate(30., coord! { x: 5., y: 5. });
+        let round_tripped = affine_from_coefficients(affine_to_coefficients(&transform));
+        assert_eq!(round_tripped, transform);
+    }
+
+    #[test]
+    fn affine_transform_with_coefficients() {
+        let arr = point_array(&[point!(x: 1., y: 2.), point!(x: -3., y: 0.5)]);
+        let arr_ref: &dyn NativeArray = &arr;
+        let coeffs = [2., 0., 0., 3., 10., -5.];
+
+        let from_coeffs = arr_ref.affine_transform(coeffs).unwrap();
+        let from_transform = arr_ref
+            .affine_transform(&affine_from_coefficients(coeffs))
+            .unwrap();
+        assert_eq!(
+            from_coeffs.as_ref().as_point(),
+            from_transform.as_ref().as_point()
+        );
+        assert_eq!(
+            from_coeffs.as_ref().as_point().value_as_geo(0),
+            point!(x: 12., y: 1.)
+        );
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let transform = affine_from_coefficients([1.5, -0.25, 0.75, 2.0, 100.125, -42.5]);
+        let inverse = affine_inverse(&transform).unwrap();
+
+        let original = coord! { x: 2., y: 4. };
+        let round_tripped = inverse.apply(transform.apply(original));
+        assert!((round_tripped.x - original.x).abs() < 1e-10);
+        assert!((round_tripped.y - original.y).abs() < 1e-10);
+
+        let singular = affine_from_coefficients([1., 2., 2., 4., 0., 0.]);
+        assert!(affine_inverse(&singular).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::BooleanArray;
+use geo::{BoundingRect, EuclideanDistance, Rect};
+
+use crate::algorithm::geo::utils::as_geometry_accessor;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{NativeGeometryAccessor, NativeScalar};
+use crate::{ArrayBase, NativeArray};
+
+/// Returns whether each pair of geometries in `left` and `right` is within `distance` of each
+/// other.
+///
+/// Rows are paired by index, so both arrays must have the same length. The output is null where
+/// either input is null. Pairs whose bounding boxes are already further apart than `distance` are
+/// rejected without computing the exact Euclidean distance.
+pub fn is_within_distance(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    distance: f64,
+) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let left = as_geometry_accessor(left)?;
+    let right = as_geometry_accessor(right)?;
+
+    let mut output_array = BooleanBuilder::with_capacity(left.len());
+    for i in 0..left.len() {
+        match (left.get_as_geometry(i), right.get_as_geometry(i)) {
+            (Some(first), Some(second)) => {
+                let first = first.to_geo_geometry();
+                let second = second.to_geo_geometry();
+                output_array.append_value(geometries_within_distance(&first, &second, distance));
+            }
+            _ => output_array.append_null(),
+        }
+    }
+
+    Ok(output_array.finish())
+}
+
+fn geometries_within_distance(
+    first: &geo::Geometry,
+    second: &geo::Geometry,
+    distance: f64,
+) -> bool {
+    if let (Some(first_rect), Some(second_rect)) = (first.bounding_rect(), second.bounding_rect()) {
+        if rect_separation(&first_rect, &second_rect) > distance {
+            return false;
+        }
+    }
+
+    first.euclidean_distance(second) <= distance
+}
+
+/// The minimum distance between two rectangles, or `0.0` if they overlap.
+fn rect_separation(first: &Rect, second: &Rect) -> f64 {
+    let dx = (first.min().x - second.max().x)
+        .max(second.min().x - first.max().x)
+        .max(0.);
+    let dy = (first.min().y - second.max().y)
+        .max(second.min().y - first.max().y)
+        .max(0.);
+    dx.hypot(dy)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::datatypes::Dimension;
+
+    #[test]
+    fn points_within_distance() {
+        let left: PointArray = (vec![geo::point!(x: 0., y: 0.)].as_slice(), Dimension::XY).into();
+        let right: PointArray = (vec![geo::point!(x: 3., y: 4.)].as_slice(), Dimension::XY).into();
+
+        assert!(is_within_distance(&left, &right, 6.).unwrap().value(0));
+        assert!(!is_within_distance(&left, &right, 4.).unwrap().value(0));
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let left = crate::test::point::point_array();
+        let right: PointArray = (vec![geo::point!(x: 3., y: 4.)].as_slice(), Dimension::XY).into();
+        assert!(is_within_distance(&left, &right, 1.).is_err());
+    }
+}
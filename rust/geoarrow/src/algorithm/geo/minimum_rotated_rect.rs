@@ -100,6 +100,13 @@ impl MinimumRotatedRect for &dyn NativeArray {
     }
 }
 
+/// Compute the minimum-area oriented bounding rectangle of each geometry in `arr`.
+///
+/// See [`MinimumRotatedRect`] for details. Null input geometries produce null output polygons.
+pub fn minimum_rotated_rect(arr: &dyn NativeArray) -> Result<PolygonArray> {
+    arr.minimum_rotated_rect()
+}
+
 impl<G: NativeArray> MinimumRotatedRect for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedGeometryArray<PolygonArray>>;
 
@@ -129,3 +136,32 @@ impl MinimumRotatedRect for &dyn ChunkedNativeArray {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, Area};
+
+    #[test]
+    fn diagonal_line_string_is_tilted() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 10.),
+            (x: 10., y: 11.),
+        ];
+        let arr: LineStringArray = (vec![Some(line_string), None], Dimension::XY).into();
+
+        let output = minimum_rotated_rect(&arr).unwrap();
+        assert!(output.get(1).is_none());
+
+        let rect: geo::Polygon = output.value(0).into();
+        // The axis-aligned bounding box has an area of 110, while the tilted rectangle hugging
+        // the diagonal is much smaller.
+        assert!(rect.unsigned_area() < 20.);
+        let edge_is_axis_aligned = rect
+            .exterior()
+            .lines()
+            .all(|line| line.dx().abs() < 1e-9 || line.dy().abs() < 1e-9);
+        assert!(!edge_is_axis_aligned);
+    }
+}
@@ -7,7 +7,9 @@ pub(crate) mod utils;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 mod affine_ops;
-pub use affine_ops::AffineOps;
+pub use affine_ops::{
+    affine_from_coefficients, affine_from_gcps, affine_inverse, affine_to_coefficients, AffineOps,
+};
 pub use geo::AffineTransform;
 
 /// Calculate the area of the surface of geometries.
@@ -78,6 +80,10 @@ pub use haversine_length::HaversineLength;
 mod intersects;
 pub use intersects::Intersects;
 
+/// Determine whether pairs of geometries are within a given distance of each other.
+mod is_within_distance;
+pub use is_within_distance::is_within_distance;
+
 /// Interpolate a point along a `LineStringArray`.
 mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
@@ -88,7 +94,7 @@ pub use line_locate_point::{LineLocatePoint, LineLocatePointScalar};
 
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 mod minimum_rotated_rect;
-pub use minimum_rotated_rect::MinimumRotatedRect;
+pub use minimum_rotated_rect::{minimum_rotated_rect, MinimumRotatedRect};
 
 /// Remove (consecutive) repeated points
 mod remove_repeated_points;
@@ -1,12 +1,11 @@
 use std::sync::Arc;
 
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
-use crate::array::LineStringArray;
+use crate::algorithm::native::par_map_geo;
 use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
-use crate::trait_::ArrayAccessor;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 use arrow_array::types::Float64Type;
 use geo::Scale as _Scale;
 
@@ -19,6 +18,16 @@ use geo::Scale as _Scale;
 /// [`Translate`](crate::algorithm::geo::Translate), or [`Rotate`](crate::algorithm::geo::Rotate),
 /// it is more efficient to compose the transformations and apply them as a single operation using
 /// the [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
+///
+/// ## Nulls
+///
+/// A null factor produces a null geometry in that row.
+///
+/// ## Panics
+///
+/// Implementations for concrete arrays panic if an `Array` factor does not have the same length as
+/// the geometry array, or if the geometry array is not 2D. The implementation for
+/// `&dyn NativeArray` returns an error for mismatched lengths instead.
 pub trait Scale: Sized {
     type Output;
 
@@ -99,59 +108,8 @@ pub trait Scale: Sized {
     ) -> Self::Output;
 }
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Scale for PointArray {
-    type Output = Self;
-
-    fn scale_xy(
-        &self,
-        x_factor: &BroadcastablePrimitive<Float64Type>,
-        y_factor: &BroadcastablePrimitive<Float64Type>,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
-
-        self.iter_geo()
-            .zip(x_factor)
-            .zip(y_factor)
-            .for_each(|((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.scale_xy(x_factor.unwrap(), y_factor.unwrap()))
-                        .as_ref(),
-                )
-            });
-
-        output_array.finish()
-    }
-
-    fn scale_around_point(
-        &self,
-        x_factor: &BroadcastablePrimitive<Float64Type>,
-        y_factor: &BroadcastablePrimitive<Float64Type>,
-        origin: geo::Point,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
-
-        self.iter_geo()
-            .zip(x_factor)
-            .zip(y_factor)
-            .for_each(|((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| {
-                            geom.scale_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                        })
-                        .as_ref(),
-                )
-            });
-
-        output_array.finish()
-    }
-}
-
-/// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+    ($type:ty, $builder_type:ty) => {
         impl Scale for $type {
             type Output = Self;
 
@@ -160,22 +118,14 @@ macro_rules! iter_geo_impl {
                 x_factor: &BroadcastablePrimitive<Float64Type>,
                 y_factor: &BroadcastablePrimitive<Float64Type>,
             ) -> Self {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
+                x_factor.check_len(self.len()).unwrap();
+                y_factor.check_len(self.len()).unwrap();
 
-                self.iter_geo().zip(x_factor).zip(y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| geom.scale_xy(x_factor.unwrap(), y_factor.unwrap()))
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+                let builder: $builder_type = par_map_geo(self, |i, geom| {
+                    Some(geom.scale_xy(x_factor.value(i)?, y_factor.value(i)?))
+                })
+                .unwrap();
+                builder.finish()
             }
 
             fn scale_around_point(
@@ -184,42 +134,25 @@ macro_rules! iter_geo_impl {
                 y_factor: &BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
             ) -> Self {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
-
-                self.iter_geo().zip(x_factor).zip(y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| {
-                                        geom.scale_around_point(
-                                            x_factor.unwrap(),
-                                            y_factor.unwrap(),
-                                            origin,
-                                        )
-                                    })
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
+                x_factor.check_len(self.len()).unwrap();
+                y_factor.check_len(self.len()).unwrap();
 
-                output_array.finish()
+                let builder: $builder_type = par_map_geo(self, |i, geom| {
+                    Some(geom.scale_around_point(x_factor.value(i)?, y_factor.value(i)?, origin))
+                })
+                .unwrap();
+                builder.finish()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray, LineStringBuilder, push_line_string);
-iter_geo_impl!(PolygonArray, PolygonBuilder, push_polygon);
-iter_geo_impl!(MultiPointArray, MultiPointBuilder, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray,
-    MultiLineStringBuilder,
-    push_multi_line_string
-);
-iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder, push_multi_polygon);
+iter_geo_impl!(PointArray, PointBuilder);
+iter_geo_impl!(LineStringArray, LineStringBuilder);
+iter_geo_impl!(PolygonArray, PolygonBuilder);
+iter_geo_impl!(MultiPointArray, MultiPointBuilder);
+iter_geo_impl!(MultiLineStringArray, MultiLineStringBuilder);
+iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder);
 
 impl Scale for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
@@ -229,6 +162,9 @@ impl Scale for &dyn NativeArray {
         x_factor: &BroadcastablePrimitive<Float64Type>,
         y_factor: &BroadcastablePrimitive<Float64Type>,
     ) -> Self::Output {
+        x_factor.check_len(self.len())?;
+        y_factor.check_len(self.len())?;
+
         macro_rules! impl_method {
             ($method:ident) => {{
                 Arc::new(self.$method().scale_xy(x_factor, y_factor))
@@ -260,6 +196,9 @@ impl Scale for &dyn NativeArray {
         y_factor: &BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
     ) -> Self::Output {
+        x_factor.check_len(self.len())?;
+        y_factor.check_len(self.len())?;
+
         macro_rules! impl_method {
             ($method:ident) => {{
                 Arc::new(
@@ -288,3 +227,61 @@ impl Scale for &dyn NativeArray {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::Float64Array;
+
+    #[test]
+    fn scale_length_mismatch() {
+        let arr = point::point_array();
+        let arr_ref: &dyn NativeArray = &arr;
+
+        let scalar: BroadcastablePrimitive<Float64Type> = 2.0.into();
+        assert_eq!(arr_ref.scale(&scalar).unwrap().len(), 3);
+
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![2., 3.]));
+        assert!(arr_ref.scale_xy(&too_short, &scalar).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_length_mismatch_concrete() {
+        let arr = polygon::p_array();
+        let scalar: BroadcastablePrimitive<Float64Type> = 2.0.into();
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![2.]));
+        let _ = arr.scale_xy(&scalar, &too_short);
+    }
+
+    #[test]
+    fn scale_matches_serial() {
+        let arr = polygon::p_array();
+        let x_factor = BroadcastablePrimitive::Array(Float64Array::from(vec![2., 0.5]));
+        let y_factor: BroadcastablePrimitive<Float64Type> = 3.0.into();
+
+        let scaled = arr.scale_xy(&x_factor, &y_factor);
+        let expected = arr
+            .iter_geo()
+            .zip(&x_factor)
+            .map(|(maybe_g, x)| maybe_g.map(|g| g.scale_xy(x.unwrap(), 3.)))
+            .collect::<Vec<_>>();
+        assert_eq!(scaled.len(), expected.len());
+        for (actual, expected) in scaled.iter_geo().zip(expected) {
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn scale_null_factor() {
+        let arr = polygon::p_array();
+        let x_factor = BroadcastablePrimitive::Array(Float64Array::from(vec![Some(2.), None]));
+        let y_factor: BroadcastablePrimitive<Float64Type> = 3.0.into();
+
+        let scaled = arr.scale_xy(&x_factor, &y_factor);
+        assert!(scaled.is_valid(0));
+        assert!(scaled.is_null(1));
+    }
+}
@@ -53,6 +53,84 @@ pub trait Simplify {
     /// assert_eq!(expected, simplified_array.value_as_geo(0))
     /// ```
     fn simplify(&self, epsilon: &f64) -> Self::Output;
+
+    /// Returns the simplified representation of a geometry, keeping at least `min_points`
+    /// coordinates in each line string and polygon ring.
+    ///
+    /// Each line string or ring is first simplified with `epsilon`. If that leaves fewer than
+    /// `min_points` coordinates, the tolerance for that line string or ring alone is halved until
+    /// enough coordinates remain. Line strings and rings that already have at most `min_points`
+    /// coordinates are left unchanged. Coordinate counts include the closing coordinate of a ring,
+    /// so a `min_points` of 4 keeps every ring a valid polygon ring.
+    fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self::Output;
+}
+
+/// The number of times the tolerance is halved before giving up and keeping the input unchanged.
+///
+/// After this many halvings the tolerance is below the precision of any coordinate.
+const MAX_TOLERANCE_HALVINGS: usize = 64;
+
+fn simplify_line_string_min_points(
+    line_string: &geo::LineString,
+    epsilon: f64,
+    min_points: usize,
+) -> geo::LineString {
+    if line_string.0.len() <= min_points {
+        return line_string.clone();
+    }
+
+    let mut epsilon = epsilon;
+    for _ in 0..MAX_TOLERANCE_HALVINGS {
+        let simplified = line_string.simplify(&epsilon);
+        if simplified.0.len() >= min_points {
+            return simplified;
+        }
+        epsilon /= 2.;
+    }
+    line_string.clone()
+}
+
+/// Simplify with a floor on the number of coordinates of each line string or ring.
+trait SimplifyMinPointsGeo {
+    fn simplify_min_points(&self, epsilon: f64, min_points: usize) -> Self;
+}
+
+impl SimplifyMinPointsGeo for geo::LineString {
+    fn simplify_min_points(&self, epsilon: f64, min_points: usize) -> Self {
+        simplify_line_string_min_points(self, epsilon, min_points)
+    }
+}
+
+impl SimplifyMinPointsGeo for geo::Polygon {
+    fn simplify_min_points(&self, epsilon: f64, min_points: usize) -> Self {
+        geo::Polygon::new(
+            simplify_line_string_min_points(self.exterior(), epsilon, min_points),
+            self.interiors()
+                .iter()
+                .map(|ring| simplify_line_string_min_points(ring, epsilon, min_points))
+                .collect(),
+        )
+    }
+}
+
+impl SimplifyMinPointsGeo for geo::MultiLineString {
+    fn simplify_min_points(&self, epsilon: f64, min_points: usize) -> Self {
+        geo::MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.simplify_min_points(epsilon, min_points))
+                .collect(),
+        )
+    }
+}
+
+impl SimplifyMinPointsGeo for geo::MultiPolygon {
+    fn simplify_min_points(&self, epsilon: f64, min_points: usize) -> Self {
+        geo::MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.simplify_min_points(epsilon, min_points))
+                .collect(),
+        )
+    }
 }
 
 /// Implementation that returns the identity
@@ -64,6 +142,10 @@ macro_rules! identity_impl {
             fn simplify(&self, _epsilon: &f64) -> Self {
                 self.clone()
             }
+
+            fn simplify_min_points(&self, _epsilon: &f64, _min_points: usize) -> Self {
+                self.clone()
+            }
         }
     };
 }
@@ -85,6 +167,17 @@ macro_rules! iter_geo_impl {
 
                 (output_geoms, Dimension::XY).into()
             }
+
+            fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self {
+                let output_geoms: Vec<Option<$geo_type>> = self
+                    .iter_geo()
+                    .map(|maybe_g| {
+                        maybe_g.map(|geom| geom.simplify_min_points(*epsilon, min_points))
+                    })
+                    .collect();
+
+                (output_geoms, Dimension::XY).into()
+            }
         }
     };
 }
@@ -116,6 +209,28 @@ impl Simplify for &dyn NativeArray {
         };
         Ok(result)
     }
+
+    fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self::Output {
+        use Dimension::*;
+        use NativeType::*;
+
+        macro_rules! impl_method {
+            ($method:ident) => {
+                Arc::new(self.$method().simplify_min_points(epsilon, min_points))
+            };
+        }
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, XY) => impl_method!(as_point),
+            LineString(_, XY) => impl_method!(as_line_string),
+            Polygon(_, XY) => impl_method!(as_polygon),
+            MultiPoint(_, XY) => impl_method!(as_multi_point),
+            MultiLineString(_, XY) => impl_method!(as_multi_line_string),
+            MultiPolygon(_, XY) => impl_method!(as_multi_polygon),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
 }
 
 impl Simplify for ChunkedGeometryArray<PointArray> {
@@ -126,6 +241,12 @@ impl Simplify for ChunkedGeometryArray<PointArray> {
             .try_into()
             .unwrap()
     }
+
+    fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self::Output {
+        self.map(|chunk| chunk.simplify_min_points(epsilon, min_points))
+            .try_into()
+            .unwrap()
+    }
 }
 
 /// Implementation that iterates over chunks
@@ -139,6 +260,12 @@ macro_rules! chunked_impl {
                     .try_into()
                     .unwrap()
             }
+
+            fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self {
+                self.map(|chunk| chunk.simplify_min_points(epsilon, min_points))
+                    .try_into()
+                    .unwrap()
+            }
         }
     };
 }
@@ -169,6 +296,28 @@ impl Simplify for &dyn ChunkedNativeArray {
         };
         Ok(result)
     }
+
+    fn simplify_min_points(&self, epsilon: &f64, min_points: usize) -> Self::Output {
+        use Dimension::*;
+        use NativeType::*;
+
+        macro_rules! impl_method {
+            ($method:ident) => {
+                Arc::new(self.$method().simplify_min_points(epsilon, min_points))
+            };
+        }
+
+        let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
+            Point(_, XY) => impl_method!(as_point),
+            LineString(_, XY) => impl_method!(as_line_string),
+            Polygon(_, XY) => impl_method!(as_polygon),
+            MultiPoint(_, XY) => impl_method!(as_multi_point),
+            MultiLineString(_, XY) => impl_method!(as_multi_line_string),
+            MultiPolygon(_, XY) => impl_method!(as_multi_polygon),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -223,4 +372,23 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn square_keeps_corners() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let input_array: PolygonArray = (vec![square.clone()].as_slice(), Dimension::XY).into();
+
+        // Plain simplification collapses the ring
+        let collapsed = input_array.simplify(&100.);
+        assert!(collapsed.value_as_geo(0).exterior().0.len() < 4);
+
+        let result_array = input_array.simplify_min_points(&100., 4);
+        assert_eq!(square, result_array.value_as_geo(0));
+    }
 }
@@ -6,7 +6,7 @@ use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
 use crate::trait_::ArrayAccessor;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 use arrow_array::types::Float64Type;
 use geo::Skew as _Skew;
 
@@ -19,6 +19,11 @@ use geo::Skew as _Skew;
 /// [`Translate`](crate::algorithm::geo::Translate), or [`Rotate`](crate::algorithm::geo::Rotate),
 /// it is more efficient to compose the transformations and apply them as a single operation using
 /// the [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
+///
+/// ## Panics
+///
+/// Implementations for concrete arrays panic if an `Array` angle does not have the same length as
+/// the geometry array. The implementation for `&dyn NativeArray` returns an error instead.
 pub trait Skew {
     type Output;
 
@@ -134,6 +139,9 @@ impl Skew for PointArray {
         x_factor: &BroadcastablePrimitive<Float64Type>,
         y_factor: &BroadcastablePrimitive<Float64Type>,
     ) -> Self {
+        x_factor.check_len(self.len()).unwrap();
+        y_factor.check_len(self.len()).unwrap();
+
         let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
 
         self.iter_geo()
@@ -156,6 +164,9 @@ impl Skew for PointArray {
         y_factor: &BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
     ) -> Self {
+        x_factor.check_len(self.len()).unwrap();
+        y_factor.check_len(self.len()).unwrap();
+
         let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
 
         self.iter_geo()
@@ -186,6 +197,9 @@ macro_rules! iter_geo_impl {
                 x_factor: &BroadcastablePrimitive<Float64Type>,
                 y_factor: &BroadcastablePrimitive<Float64Type>,
             ) -> Self {
+                x_factor.check_len(self.len()).unwrap();
+                y_factor.check_len(self.len()).unwrap();
+
                 let mut output_array =
                     <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
 
@@ -210,6 +224,9 @@ macro_rules! iter_geo_impl {
                 y_factor: &BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
             ) -> Self {
+                x_factor.check_len(self.len()).unwrap();
+                y_factor.check_len(self.len()).unwrap();
+
                 let mut output_array =
                     <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
 
@@ -255,6 +272,9 @@ impl Skew for &dyn NativeArray {
         degrees_x: &BroadcastablePrimitive<Float64Type>,
         degrees_y: &BroadcastablePrimitive<Float64Type>,
     ) -> Self::Output {
+        degrees_x.check_len(self.len())?;
+        degrees_y.check_len(self.len())?;
+
         macro_rules! impl_method {
             ($method:ident) => {{
                 Arc::new(self.$method().skew_xy(degrees_x, degrees_y))
@@ -288,6 +308,9 @@ impl Skew for &dyn NativeArray {
         degrees_y: &BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
     ) -> Self::Output {
+        degrees_x.check_len(self.len())?;
+        degrees_y.check_len(self.len())?;
+
         macro_rules! impl_method {
             ($method:ident) => {{
                 Arc::new(
@@ -316,3 +339,28 @@ impl Skew for &dyn NativeArray {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+    use arrow_array::Float64Array;
+
+    #[test]
+    fn skew_length_mismatch() {
+        let arr = point::point_array();
+        let arr_ref: &dyn NativeArray = &arr;
+        let scalar: BroadcastablePrimitive<Float64Type> = 10.0.into();
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![10., 20.]));
+        assert!(arr_ref.skew_xy(&too_short, &scalar).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn skew_length_mismatch_concrete() {
+        let arr = polygon::p_array();
+        let scalar: BroadcastablePrimitive<Float64Type> = 10.0.into();
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![10.]));
+        let _ = arr.skew_around_point(&scalar, &too_short, geo::Point::new(0., 0.));
+    }
+}
@@ -5,7 +5,7 @@ use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
 use crate::trait_::ArrayAccessor;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 use arrow_array::types::Float64Type;
 use geo::Translate as _Translate;
 
@@ -23,6 +23,12 @@ pub trait Translate {
     /// transformations and apply them as a single operation using the
     /// [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
     ///
+    /// # Panics
+    ///
+    /// Implementations for concrete arrays panic if an `Array` offset does not have the same
+    /// length as the geometry array. The implementation for `&dyn NativeArray` returns an error
+    /// instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -60,6 +66,9 @@ impl Translate for PointArray {
         x_offset: &BroadcastablePrimitive<Float64Type>,
         y_offset: &BroadcastablePrimitive<Float64Type>,
     ) -> Self {
+        x_offset.check_len(self.len()).unwrap();
+        y_offset.check_len(self.len()).unwrap();
+
         let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
 
         self.iter_geo()
@@ -88,6 +97,9 @@ macro_rules! iter_geo_impl {
                 x_offset: &BroadcastablePrimitive<Float64Type>,
                 y_offset: &BroadcastablePrimitive<Float64Type>,
             ) -> Self {
+                x_offset.check_len(self.len()).unwrap();
+                y_offset.check_len(self.len()).unwrap();
+
                 let mut output_array =
                     <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
 
@@ -129,6 +141,9 @@ impl Translate for &dyn NativeArray {
         x_offset: &BroadcastablePrimitive<Float64Type>,
         y_offset: &BroadcastablePrimitive<Float64Type>,
     ) -> Self::Output {
+        x_offset.check_len(self.len())?;
+        y_offset.check_len(self.len())?;
+
         macro_rules! impl_method {
             ($method:ident) => {{
                 Arc::new(self.$method().translate(x_offset, y_offset))
@@ -154,3 +169,28 @@ impl Translate for &dyn NativeArray {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+    use arrow_array::Float64Array;
+
+    #[test]
+    fn translate_length_mismatch() {
+        let arr = point::point_array();
+        let arr_ref: &dyn NativeArray = &arr;
+        let scalar: BroadcastablePrimitive<Float64Type> = 1.0.into();
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![1., 2.]));
+        assert!(arr_ref.translate(&too_short, &scalar).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn translate_length_mismatch_concrete() {
+        let arr = polygon::p_array();
+        let scalar: BroadcastablePrimitive<Float64Type> = 1.0.into();
+        let too_short = BroadcastablePrimitive::Array(Float64Array::from(vec![1.]));
+        let _ = arr.translate(&scalar, &too_short);
+    }
+}
@@ -1,7 +1,31 @@
 use arrow_array::Float64Array;
 use arrow_buffer::NullBuffer;
 
+use crate::array::AsNativeArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::NativeGeometryAccessor;
+use crate::NativeArray;
+
 pub(crate) fn zeroes(len: usize, nulls: Option<&NullBuffer>) -> Float64Array {
     let values = vec![0.0f64; len];
     Float64Array::new(values.into(), nulls.cloned())
 }
+
+/// Downcast a [`NativeArray`] to a [`NativeGeometryAccessor`] over its concrete array type.
+pub(crate) fn as_geometry_accessor(array: &dyn NativeArray) -> Result<&dyn NativeGeometryAccessor> {
+    use NativeType::*;
+
+    let accessor: &dyn NativeGeometryAccessor = match array.data_type() {
+        Point(_, _) => array.as_point(),
+        LineString(_, _) => array.as_line_string(),
+        Polygon(_, _) => array.as_polygon(),
+        MultiPoint(_, _) => array.as_multi_point(),
+        MultiLineString(_, _) => array.as_multi_line_string(),
+        MultiPolygon(_, _) => array.as_multi_polygon(),
+        Mixed(_, _) => array.as_mixed(),
+        GeometryCollection(_, _) => array.as_geometry_collection(),
+        Rect(_) => return Err(GeoArrowError::IncorrectType("".into())),
+    };
+    Ok(accessor)
+}
@@ -1,28 +1,56 @@
-use crate::algorithm::geos::util::try_unary_polygon;
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geos::util::try_broadcast_polygon;
 use crate::array::{PointArray, PolygonArray};
 use crate::error::Result;
 use crate::NativeArray;
+use arrow_array::types::Float64Type;
 use geos::{BufferParams, Geom};
 
+/// Buffer geometries by a distance.
+///
+/// The distance can either be a scalar, applied to every geometry, or an array with one distance
+/// per geometry. The output is null where either the geometry or its distance is null.
 pub trait Buffer {
     type Output;
 
-    fn buffer(&self, width: f64, quadsegs: i32) -> Self::Output;
+    fn buffer(
+        &self,
+        width: impl Into<BroadcastablePrimitive<Float64Type>>,
+        quadsegs: i32,
+    ) -> Self::Output;
 
-    fn buffer_with_params(&self, width: f64, buffer_params: &BufferParams) -> Self::Output;
+    fn buffer_with_params(
+        &self,
+        width: impl Into<BroadcastablePrimitive<Float64Type>>,
+        buffer_params: &BufferParams,
+    ) -> Self::Output;
 }
 
 impl Buffer for PointArray {
     type Output = Result<PolygonArray>;
 
-    fn buffer(&self, width: f64, quadsegs: i32) -> Self::Output {
-        try_unary_polygon(self, |g| g.buffer(width, quadsegs), self.dimension())
+    fn buffer(
+        &self,
+        width: impl Into<BroadcastablePrimitive<Float64Type>>,
+        quadsegs: i32,
+    ) -> Self::Output {
+        try_broadcast_polygon(
+            self,
+            &width.into(),
+            |g, width| g.buffer(width, quadsegs),
+            self.dimension(),
+        )
     }
 
-    fn buffer_with_params(&self, width: f64, buffer_params: &BufferParams) -> Self::Output {
-        try_unary_polygon(
+    fn buffer_with_params(
+        &self,
+        width: impl Into<BroadcastablePrimitive<Float64Type>>,
+        buffer_params: &BufferParams,
+    ) -> Self::Output {
+        try_broadcast_polygon(
             self,
-            |g| g.buffer_with_params(width, buffer_params),
+            &width.into(),
+            |g, width| g.buffer_with_params(width, buffer_params),
             self.dimension(),
         )
     }
@@ -31,12 +59,38 @@ impl Buffer for PointArray {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::algorithm::geo::Area;
     use crate::test::point::point_array;
+    use crate::ArrayBase;
+    use arrow_array::Float64Array;
 
     #[test]
     fn point_buffer() {
         let arr = point_array();
-        let buffered: PolygonArray = arr.buffer(1., 8).unwrap();
-        dbg!(buffered);
+        let buffered: PolygonArray = arr.buffer(1.0, 8).unwrap();
+        assert_eq!(buffered.len(), arr.len());
+    }
+
+    #[test]
+    fn point_buffer_per_row() {
+        let arr = point_array().slice(0, 2);
+        let widths = BroadcastablePrimitive::Array(Float64Array::from(vec![1.0, 2.0]));
+        let buffered: PolygonArray = arr.buffer(widths, 32).unwrap();
+
+        let areas = buffered.unsigned_area();
+        // A buffered point is a polygon approximating a circle, so its area is slightly less than
+        // that of the circle.
+        let expected = [std::f64::consts::PI, 4. * std::f64::consts::PI];
+        for (area, expected) in areas.values().iter().zip(expected) {
+            assert!(area < &expected);
+            assert!((area - expected).abs() / expected < 0.01);
+        }
+    }
+
+    #[test]
+    fn point_buffer_length_mismatch() {
+        let arr = point_array();
+        let widths = BroadcastablePrimitive::Array(Float64Array::from(vec![1.0, 2.0]));
+        assert!(arr.buffer(widths, 8).is_err());
     }
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use geos::Geom;
+
+use crate::algorithm::geos::util::native_array_from_geos;
+use crate::array::PolygonArray;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Union all polygons of a polygonal coverage into a single geometry.
+///
+/// A coverage is a set of valid polygons that do not overlap and that share edges exactly, such as
+/// the cells of a tessellation or administrative boundaries. For such input this is much faster
+/// than a general unary union: shared edges are found by matching segments, which is roughly
+/// linear in the number of vertices, instead of running a full overlay between polygons.
+///
+/// The input is assumed to be a valid coverage. If it is not, the output may be invalid; GEOS does
+/// not check. The output has a single row, and null rows are skipped.
+pub fn coverage_union(arr: &PolygonArray) -> Result<Arc<dyn NativeArray>> {
+    let mut polygons = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        if let Some(polygon) = arr.get_as_geometry(i)? {
+            polygons.push(polygon);
+        }
+    }
+
+    let coverage = geos::Geometry::create_multipolygon(polygons)?;
+    native_array_from_geos(vec![Some(coverage.coverage_union()?)])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsNativeArray;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::{polygon, Area, BoundingRect};
+
+    #[test]
+    fn edge_sharing_squares() {
+        let left = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let right = polygon![
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+        ];
+        let arr: PolygonArray = (vec![left, right].as_slice(), Dimension::XY).into();
+
+        let output = coverage_union(&arr).unwrap();
+        let output = output.as_ref().as_polygon();
+        assert_eq!(output.len(), 1);
+
+        let rectangle: geo::Polygon = output.value(0).into();
+        assert_eq!(rectangle.unsigned_area(), 2.);
+        assert_eq!(
+            rectangle.bounding_rect().unwrap(),
+            geo::Rect::new((0., 0.), (2., 1.))
+        );
+    }
+}
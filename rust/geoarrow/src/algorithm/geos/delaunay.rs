@@ -0,0 +1,52 @@
+use geos::Geom;
+
+use crate::algorithm::geos::util::geometry_collection_from_geos;
+use crate::array::{GeometryCollectionArray, MultiPointArray};
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::ArrayBase;
+
+/// Compute the Delaunay triangulation of the points in each multi point.
+///
+/// Each output row is a geometry collection of triangular polygons. Input points closer together
+/// than `tolerance` are snapped together before triangulating.
+///
+/// Null input yields null output.
+pub fn delaunay_triangulation(
+    points: &MultiPointArray,
+    tolerance: f64,
+) -> Result<GeometryCollectionArray> {
+    let mut output = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let triangles = points
+            .get_as_geometry(i)?
+            .map(|geom| geom.delaunay_triangulation(tolerance, false))
+            .transpose()?;
+        output.push(triangles);
+    }
+
+    geometry_collection_from_geos(output, Dimension::XY)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+    use geo::{point, MultiPoint};
+    use geo_traits::GeometryCollectionTrait;
+
+    #[test]
+    fn square_corners() {
+        let multi_point = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 0., y: 1.),
+        ]);
+        let arr: MultiPointArray = (vec![multi_point].as_slice(), Dimension::XY).into();
+
+        let output = delaunay_triangulation(&arr, 0.).unwrap();
+        assert_eq!(output.value(0).num_geometries(), 2);
+    }
+}
@@ -0,0 +1,79 @@
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::BooleanArray;
+use geos::Geom;
+
+use crate::algorithm::geos::util::as_geos_accessor;
+use crate::error::{GeoArrowError, Result};
+use crate::{ArrayBase, NativeArray};
+
+/// Pairwise topological equality of two geometry arrays.
+///
+/// Unlike [`PartialEq`] on arrays, which compares coordinates exactly, this uses GEOS'
+/// `equals` predicate. Two geometries are equal if they cover the same points in space, so a
+/// linestring equals its reverse and a polygon equals a version of itself with a rotated starting
+/// vertex.
+///
+/// The output is null where either input is null.
+pub fn geom_eq(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let left = as_geos_accessor(left)?;
+    let right = as_geos_accessor(right)?;
+
+    let mut builder = BooleanBuilder::with_capacity(left.len());
+    for i in 0..left.len() {
+        match (left.get_as_geometry(i)?, right.get_as_geometry(i)?) {
+            (Some(left_geom), Some(right_geom)) => {
+                builder.append_value(left_geom.equals(&right_geom)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use geo::polygon;
+
+    #[test]
+    fn polygon_equals_rotated_ring() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let rotated = polygon![
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+        ];
+        let shifted = polygon![
+            (x: 5., y: 5.),
+            (x: 6., y: 5.),
+            (x: 6., y: 6.),
+            (x: 5., y: 6.),
+        ];
+
+        let left: PolygonArray = (vec![polygon.clone(), polygon].as_slice(), Dimension::XY).into();
+        let right: PolygonArray = (vec![rotated, shifted].as_slice(), Dimension::XY).into();
+
+        // Exact comparison sees different coordinates
+        assert_ne!(left, right);
+
+        let result = geom_eq(&left, &right).unwrap();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+}
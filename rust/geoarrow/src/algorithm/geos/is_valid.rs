@@ -1,11 +1,13 @@
+use crate::algorithm::geos::util::as_geos_accessor;
 use crate::algorithm::native::Unary;
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
-use crate::datatypes::NativeType;
+use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
-use crate::trait_::NativeScalar;
-use crate::NativeArray;
-use arrow_array::BooleanArray;
+use crate::trait_::{NativeGEOSGeometryAccessor, NativeScalar};
+use crate::{ArrayBase, NativeArray};
+use arrow_array::builder::{BooleanBuilder, StringBuilder};
+use arrow_array::{BooleanArray, StringArray};
 use geos::Geom;
 
 /// Checks if the geometry is valid
@@ -70,3 +72,96 @@ impl<G: NativeArray> IsValid for ChunkedGeometryArray<G> {
         Ok(ChunkedArray::new(output_chunks))
     }
 }
+
+/// Checks whether each geometry is valid, and if not, why.
+///
+/// Returns three arrays of the same length as the input:
+///
+/// - whether each geometry is valid,
+/// - the reason GEOS gives for its validity, such as `"Valid Geometry"` or `"Self-intersection"`,
+/// - the location of the first validity problem found, which is null for valid geometries.
+///
+/// All three outputs are null where the input is null.
+pub fn validity_reason(arr: &dyn NativeArray) -> Result<(BooleanArray, StringArray, PointArray)> {
+    let accessor = as_geos_accessor(arr)?;
+
+    let mut valid_builder = BooleanBuilder::with_capacity(arr.len());
+    let mut reason_builder = StringBuilder::with_capacity(arr.len(), 0);
+    let mut locations = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        if let Some(geom) = accessor.get_as_geometry(i)? {
+            let is_valid = geom.is_valid();
+            let (reason, location) = parse_validity_reason(&geom.is_valid_reason()?);
+            valid_builder.append_value(is_valid);
+            reason_builder.append_value(reason);
+            locations.push(if is_valid { None } else { location });
+        } else {
+            valid_builder.append_null();
+            reason_builder.append_null();
+            locations.push(None);
+        }
+    }
+
+    let locations: PointArray = (locations, Dimension::XY).into();
+    Ok((valid_builder.finish(), reason_builder.finish(), locations))
+}
+
+/// Split a GEOS validity reason such as `"Self-intersection[0.5 0.5]"` into its description and
+/// location.
+fn parse_validity_reason(reason: &str) -> (&str, Option<geo::Point>) {
+    let Some((description, rest)) = reason.split_once('[') else {
+        return (reason, None);
+    };
+
+    let location = rest
+        .trim_end_matches(']')
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let location = match location.as_slice() {
+        [x, y, ..] => match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => Some(geo::Point::new(x, y)),
+            _ => None,
+        },
+        _ => None,
+    };
+    (description.trim(), location)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+    use geo::polygon;
+
+    #[test]
+    fn bowtie_polygon() {
+        let valid = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        // A bowtie whose edges cross at (1, 1)
+        let bowtie = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+        ];
+        let arr: PolygonArray = (vec![Some(valid), Some(bowtie), None], Dimension::XY).into();
+
+        let (is_valid, reasons, locations) = validity_reason(&arr).unwrap();
+
+        assert!(is_valid.value(0));
+        assert!(locations.is_null(0));
+
+        assert!(!is_valid.value(1));
+        assert_eq!(reasons.value(1), "Self-intersection");
+        let location: geo::Point = locations.value(1).into();
+        assert_eq!(location, geo::point!(x: 1., y: 1.));
+
+        assert!(is_valid.is_null(2));
+        assert!(reasons.is_null(2));
+        assert!(locations.is_null(2));
+    }
+}
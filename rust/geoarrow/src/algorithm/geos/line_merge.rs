@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use geos::Geom;
+
+use crate::algorithm::geos::util::native_array_from_geos;
+use crate::array::MultiLineStringArray;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Merge the line strings of each multi line string into maximal line strings.
+///
+/// Segments that share endpoints are joined together. Rows that merge into a single line string
+/// become line strings, so the output is downcast to the narrowest array type that can hold every
+/// row.
+///
+/// Null input yields null output.
+pub fn line_merge(arr: &MultiLineStringArray) -> Result<Arc<dyn NativeArray>> {
+    let mut output = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let merged = arr
+            .get_as_geometry(i)?
+            .map(|geom| geom.line_merge())
+            .transpose()?;
+        output.push(merged);
+    }
+
+    native_array_from_geos(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::io::geo::to_geo_vec;
+    use geo::{line_string, MultiLineString};
+
+    #[test]
+    fn merge_connected_segments() {
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+        ]);
+        let arr: MultiLineStringArray = (vec![multi_line_string].as_slice(), Dimension::XY).into();
+
+        let output = line_merge(&arr).unwrap();
+        let geoms = to_geo_vec(output.as_ref());
+        let expected = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(geoms, vec![Some(geo::Geometry::LineString(expected))]);
+    }
+}
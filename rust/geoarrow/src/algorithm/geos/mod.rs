@@ -2,17 +2,45 @@
 
 mod area;
 mod buffer;
+mod coverage_union;
+mod delaunay;
+mod equals;
 mod is_empty;
 mod is_ring;
 mod is_simple;
 mod is_valid;
 mod length;
+mod line_merge;
+mod offset_curve;
+mod overlay;
+mod point_on_surface;
+mod polygonize;
+mod prepared_contains;
+mod relate;
+mod sjoin;
+mod snap;
+mod topology_preserving_simplify;
 mod util;
+mod voronoi;
 
 pub use area::Area;
 pub use buffer::Buffer;
+pub use coverage_union::coverage_union;
+pub use delaunay::delaunay_triangulation;
+pub use equals::geom_eq;
 pub use is_empty::IsEmpty;
 pub use is_ring::IsRing;
 pub use is_simple::IsSimple;
-pub use is_valid::IsValid;
+pub use is_valid::{validity_reason, IsValid};
 pub use length::Length;
+pub use line_merge::line_merge;
+pub use offset_curve::offset_curve;
+pub use overlay::{difference, intersection, sym_difference};
+pub use point_on_surface::point_on_surface;
+pub use polygonize::polygonize;
+pub use prepared_contains::prepared_contains;
+pub use relate::relate;
+pub use sjoin::{sjoin_geos, SpatialPredicate};
+pub use snap::snap;
+pub use topology_preserving_simplify::topology_preserving_simplify;
+pub use voronoi::voronoi;
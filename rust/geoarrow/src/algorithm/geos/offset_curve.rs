@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use geos::{Geom, JoinStyle};
+
+use crate::algorithm::geos::util::native_array_from_geos;
+use crate::array::LineStringArray;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// The mitre ratio limit used by GEOS when none is specified.
+const DEFAULT_MITRE_LIMIT: f64 = 5.0;
+
+/// Compute a line parallel to each line string in `arr`, offset by `distance`.
+///
+/// A positive `distance` offsets to the left of the line's direction and a negative `distance`
+/// offsets to the right. `quad_segs` is the number of segments used to approximate a quarter
+/// circle at round joins. Nulls are preserved.
+///
+/// The output is usually a line string array, but the offset of a line string can be a multi line
+/// string, in which case a multi line string or mixed array is returned.
+pub fn offset_curve(
+    arr: &LineStringArray,
+    distance: f64,
+    quad_segs: i32,
+    join_style: JoinStyle,
+) -> Result<Arc<dyn NativeArray>> {
+    let mut output = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let geom = arr
+            .get_as_geometry(i)?
+            .map(|geom| geom.offset_curve(distance, quad_segs, join_style, DEFAULT_MITRE_LIMIT))
+            .transpose()?;
+        output.push(geom);
+    }
+
+    native_array_from_geos(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsNativeArray;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::line_string;
+
+    #[test]
+    fn offset_straight_segment() {
+        let arr: LineStringArray = (
+            vec![Some(line_string![(x: 0., y: 0.), (x: 10., y: 0.)]), None],
+            Dimension::XY,
+        )
+            .into();
+
+        let left = offset_curve(&arr, 2., 8, JoinStyle::Round).unwrap();
+        assert!(left.is_null(1));
+        let left: geo::LineString = left.as_ref().as_line_string().value(0).into();
+        assert!(left.0.len() >= 2);
+        for coord in left.coords() {
+            assert!((coord.y - 2.).abs() < 1e-9);
+            assert!((0. ..=10.).contains(&coord.x));
+        }
+
+        let right = offset_curve(&arr, -2., 8, JoinStyle::Round).unwrap();
+        let right: geo::LineString = right.as_ref().as_line_string().value(0).into();
+        for coord in right.coords() {
+            assert!((coord.y + 2.).abs() < 1e-9);
+        }
+    }
+}
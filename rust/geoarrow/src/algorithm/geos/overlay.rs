@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use geos::Geom;
+
+use crate::algorithm::geos::util::{as_geos_accessor, native_array_from_geos};
+use crate::error::{GeoArrowError, Result};
+use crate::{ArrayBase, NativeArray};
+
+/// Apply a GEOS overlay operation to each pair of geometries in `left` and `right`.
+///
+/// The output is null where either input is null or where the result is empty.
+fn overlay<F>(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: F,
+) -> Result<Arc<dyn NativeArray>>
+where
+    F: Fn(&geos::Geometry, &geos::Geometry) -> std::result::Result<geos::Geometry, geos::Error>,
+{
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let left = as_geos_accessor(left)?;
+    let right = as_geos_accessor(right)?;
+
+    let mut output = Vec::with_capacity(left.len());
+    for i in 0..left.len() {
+        let geom = match (left.get_as_geometry(i)?, right.get_as_geometry(i)?) {
+            (Some(left_geom), Some(right_geom)) => {
+                let result = op(&left_geom, &right_geom)?;
+                if result.is_empty()? {
+                    None
+                } else {
+                    Some(result)
+                }
+            }
+            _ => None,
+        };
+        output.push(geom);
+    }
+
+    native_array_from_geos(output)
+}
+
+/// Pairwise geometric intersection of two geometry arrays.
+///
+/// The output type depends on the results and is downcast to the narrowest array type that can
+/// hold them. The output is null where either input is null or the intersection is empty.
+pub fn intersection(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> Result<Arc<dyn NativeArray>> {
+    overlay(left, right, |left_geom, right_geom| {
+        left_geom.intersection(right_geom)
+    })
+}
+
+/// Pairwise geometric difference of two geometry arrays, i.e. the parts of `left` not in `right`.
+///
+/// The output type depends on the results and is downcast to the narrowest array type that can
+/// hold them. The output is null where either input is null or the difference is empty.
+pub fn difference(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+    overlay(left, right, |left_geom, right_geom| {
+        left_geom.difference(right_geom)
+    })
+}
+
+/// Pairwise symmetric difference of two geometry arrays, i.e. the parts of either geometry not
+/// shared with the other.
+///
+/// The output type depends on the results and is downcast to the narrowest array type that can
+/// hold them. The output is null where either input is null or the difference is empty.
+pub fn sym_difference(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> Result<Arc<dyn NativeArray>> {
+    overlay(left, right, |left_geom, right_geom| {
+        left_geom.sym_difference(right_geom)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsNativeArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::{polygon, Area};
+
+    fn square(min: f64, max: f64) -> geo::Polygon {
+        polygon![
+            (x: min, y: min),
+            (x: max, y: min),
+            (x: max, y: max),
+            (x: min, y: max),
+        ]
+    }
+
+    fn polygon_array(polygons: &[geo::Polygon]) -> PolygonArray {
+        (polygons, Dimension::XY).into()
+    }
+
+    #[test]
+    fn intersection_overlapping_squares() {
+        let left = polygon_array(&[square(0., 2.), square(0., 1.)]);
+        let right = polygon_array(&[square(1., 3.), square(5., 6.)]);
+
+        let output = intersection(&left, &right).unwrap();
+        let output = output.as_ref().as_polygon();
+        let overlap: geo::Polygon = output.value(0).into();
+        assert_eq!(overlap.unsigned_area(), 1.);
+
+        // Disjoint squares have an empty intersection
+        assert!(output.is_null(1));
+    }
+
+    #[test]
+    fn difference_overlapping_squares() {
+        let left = polygon_array(&[square(0., 2.), square(0., 1.)]);
+        let right = polygon_array(&[square(1., 3.), square(0., 1.)]);
+
+        let output = difference(&left, &right).unwrap();
+        let output = output.as_ref().as_polygon();
+        let remainder: geo::Polygon = output.value(0).into();
+        // The left square has area 4 and overlaps the right square by 1
+        assert_eq!(remainder.unsigned_area(), 4. - 1.);
+
+        // Identical squares have an empty difference
+        assert!(output.is_null(1));
+    }
+
+    #[test]
+    fn sym_difference_overlapping_squares() {
+        let left = polygon_array(&[square(0., 2.)]);
+        let right = polygon_array(&[square(1., 3.)]);
+
+        let output = sym_difference(&left, &right).unwrap();
+        let output = output.as_ref().as_multi_polygon();
+        let remainder: geo::MultiPolygon = output.value(0).into();
+        assert_eq!(remainder.unsigned_area(), 4. + 4. - 2.);
+    }
+}
@@ -0,0 +1,59 @@
+use geos::Geom;
+
+use crate::algorithm::geos::util::as_geos_accessor;
+use crate::array::PointArray;
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::{ArrayBase, NativeArray};
+
+/// Compute a point guaranteed to lie in the interior of each geometry.
+///
+/// Unlike a centroid, which can fall outside of concave polygons, the returned point always
+/// intersects its input geometry, which makes it suitable for placing labels.
+///
+/// Null input yields null output.
+pub fn point_on_surface(arr: &dyn NativeArray) -> Result<PointArray> {
+    let accessor = as_geos_accessor(arr)?;
+
+    let mut output = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let point = accessor
+            .get_as_geometry(i)?
+            .map(|geom| geom.point_on_surface())
+            .transpose()?;
+        output.push(point);
+    }
+
+    PointArray::from_geos(output, Dimension::XY)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::trait_::ArrayAccessor;
+    use geo::{polygon, Centroid, Contains};
+
+    #[test]
+    fn c_shaped_polygon() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 9.),
+            (x: 10., y: 9.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        // The centroid of this shape falls in its empty middle
+        assert!(!polygon.contains(&polygon.centroid().unwrap()));
+
+        let arr: PolygonArray = (vec![Some(polygon.clone()), None], Dimension::XY).into();
+
+        let output = point_on_surface(&arr).unwrap();
+        let point: geo::Point = output.value(0).into();
+        assert!(polygon.contains(&point));
+        assert!(output.is_null(1));
+    }
+}
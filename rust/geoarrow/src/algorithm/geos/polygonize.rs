@@ -0,0 +1,62 @@
+use crate::array::{MultiPolygonArray, MultiPolygonBuilder};
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geos::to_geos_vec;
+use crate::{ArrayBase, NativeArray};
+
+/// Form polygons from the linework of all geometries in `arr`.
+///
+/// The line strings of every row are collected together, so the output has a single row holding
+/// every polygon that can be formed. The input must be correctly noded, i.e. line strings may only
+/// touch at their endpoints. Null rows are skipped.
+pub fn polygonize(arr: &dyn NativeArray) -> Result<MultiPolygonArray> {
+    let geoms = to_geos_vec(arr)?.into_iter().flatten().collect::<Vec<_>>();
+
+    let polygons = geos::Geometry::polygonize(&geoms)?;
+    // GEOS returns a geometry collection whose members are all polygons
+    let polygons = match geo::Geometry::try_from(polygons)? {
+        geo::Geometry::GeometryCollection(collection) => collection
+            .into_iter()
+            .map(geo::Polygon::try_from)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|err| GeoArrowError::General(err.to_string()))?,
+        geom => {
+            return Err(GeoArrowError::General(format!(
+                "Expected a geometry collection from polygonize, got {geom:?}"
+            )))
+        }
+    };
+
+    Ok(MultiPolygonBuilder::from_multi_polygons(
+        &[geo::MultiPolygon::new(polygons)],
+        Dimension::XY,
+        Default::default(),
+        arr.metadata(),
+    )
+    .finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::LineStringArray;
+    use crate::trait_::ArrayAccessor;
+    use geo::{line_string, Area};
+
+    #[test]
+    fn closed_square() {
+        let segments = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 1.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 1.), (x: 0., y: 0.)],
+        ];
+        let arr: LineStringArray = (segments.as_slice(), Dimension::XY).into();
+
+        let output = polygonize(&arr).unwrap();
+        assert_eq!(output.len(), 1);
+        let multi_polygon: geo::MultiPolygon = output.value(0).into();
+        assert_eq!(multi_polygon.0.len(), 1);
+        assert_eq!(multi_polygon.unsigned_area(), 1.);
+    }
+}
@@ -0,0 +1,67 @@
+use arrow_array::BooleanArray;
+use geo_traits::to_geo::ToGeoPolygon;
+use geo_traits::PolygonTrait;
+use geos::Geom;
+
+use crate::array::PointArray;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::ArrayBase;
+
+/// Test whether `polygon` contains each point of `points`.
+///
+/// This is intended for testing a single polygon against many points: the polygon is converted
+/// to a GEOS prepared geometry once, which builds spatial indexes of its edges so that each
+/// subsequent test is much cheaper than a plain `contains` predicate.
+///
+/// Null points yield null output.
+pub fn prepared_contains(
+    polygon: &impl PolygonTrait<T = f64>,
+    points: &PointArray,
+) -> Result<BooleanArray> {
+    let polygon = geos::Geometry::try_from(&polygon.to_polygon())?;
+    let prepared = polygon.to_prepared_geom()?;
+
+    let mut output = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let contains = points
+            .get_as_geometry(i)?
+            .map(|point| prepared.contains(&point))
+            .transpose()?;
+        output.push(contains);
+    }
+    Ok(BooleanArray::from(output))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use geo::{point, polygon};
+
+    #[test]
+    fn points_in_square() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let points: PointArray = (
+            vec![
+                Some(point!(x: 5., y: 5.)),
+                Some(point!(x: 15., y: 5.)),
+                None,
+                Some(point!(x: 0., y: 5.)),
+            ],
+            Dimension::XY,
+        )
+            .into();
+
+        let output = prepared_contains(&polygon, &points).unwrap();
+        assert_eq!(
+            output,
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)])
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use arrow_array::builder::StringBuilder;
+use arrow_array::StringArray;
+use geos::Geom;
+
+use crate::algorithm::geos::util::as_geos_accessor;
+use crate::error::{GeoArrowError, Result};
+use crate::{ArrayBase, NativeArray};
+
+/// Compute the DE-9IM intersection matrix between each pair of geometries in `left` and `right`.
+///
+/// Each output value is a 9-character string such as `"212101212"`, which can be matched against
+/// a pattern to implement custom spatial predicates. Both arrays must have the same length. The
+/// output is null where either input is null.
+pub fn relate(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<StringArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let left = as_geos_accessor(left)?;
+    let right = as_geos_accessor(right)?;
+
+    let mut builder = StringBuilder::with_capacity(left.len(), left.len() * 9);
+    for i in 0..left.len() {
+        match (left.get_as_geometry(i)?, right.get_as_geometry(i)?) {
+            (Some(left_geom), Some(right_geom)) => {
+                builder.append_value(left_geom.relate(&right_geom)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use geo::polygon;
+
+    #[test]
+    fn overlapping_polygons() {
+        let left = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let right = polygon![
+            (x: 1., y: 1.),
+            (x: 3., y: 1.),
+            (x: 3., y: 3.),
+            (x: 1., y: 3.),
+        ];
+        let left: PolygonArray = (vec![left].as_slice(), Dimension::XY).into();
+        let right: PolygonArray = (vec![right].as_slice(), Dimension::XY).into();
+
+        let matrix = relate(&left, &right).unwrap();
+        assert_eq!(matrix.value(0), "212101212");
+    }
+}
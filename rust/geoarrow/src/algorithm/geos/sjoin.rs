@@ -0,0 +1,141 @@
+use arrow_array::UInt32Array;
+use geos::{Geom, PreparedGeometry, STRtree, SpatialIndex};
+
+use crate::error::Result;
+use crate::io::geos::to_geos_vec;
+use crate::NativeArray;
+
+/// A binary spatial predicate evaluated by GEOS, in the sense of `predicate(left, right)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialPredicate {
+    Intersects,
+    Contains,
+    Within,
+    Covers,
+    CoveredBy,
+    Touches,
+    Crosses,
+    Overlaps,
+}
+
+impl SpatialPredicate {
+    fn evaluate(
+        &self,
+        left: &PreparedGeometry,
+        right: &geos::Geometry,
+    ) -> std::result::Result<bool, geos::Error> {
+        match self {
+            Self::Intersects => left.intersects(right),
+            Self::Contains => left.contains(right),
+            Self::Within => left.within(right),
+            Self::Covers => left.covers(right),
+            Self::CoveredBy => left.covered_by(right),
+            Self::Touches => left.touches(right),
+            Self::Crosses => left.crosses(right),
+            Self::Overlaps => left.overlaps(right),
+        }
+    }
+}
+
+/// Find all pairs of rows `(i, j)` where `predicate(left[i], right[j])` holds.
+///
+/// The geometries of `right` are loaded into a GEOS sort-tile-recursive (STR) packed R-tree,
+/// which is queried with the envelope of each geometry of `left`. Candidate pairs are then
+/// refined with GEOS, using a prepared geometry for each left geometry.
+///
+/// Null and empty geometries never match. The returned pairs are sorted by left index and then
+/// by right index.
+pub fn sjoin_geos(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    predicate: SpatialPredicate,
+) -> Result<(UInt32Array, UInt32Array)> {
+    let left_geoms = to_geos_vec(left)?;
+    let right_geoms = to_geos_vec(right)?;
+
+    // 10 is the default node capacity of the GEOS STRtree
+    let mut tree = STRtree::with_capacity(10)?;
+    for (row, geom) in right_geoms.iter().enumerate() {
+        if let Some(geom) = geom {
+            if !geom.is_empty()? {
+                tree.insert(geom, row);
+            }
+        }
+    }
+
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    for (left_row, left_geom) in left_geoms.iter().enumerate() {
+        let Some(left_geom) = left_geom else {
+            continue;
+        };
+        if left_geom.is_empty()? {
+            continue;
+        }
+
+        let mut candidates = vec![];
+        tree.query(left_geom, |right_row: &usize| candidates.push(*right_row));
+        candidates.sort_unstable();
+
+        let prepared = left_geom.to_prepared_geom()?;
+        for right_row in candidates {
+            let right_geom = right_geoms[right_row].as_ref().unwrap();
+            if predicate.evaluate(&prepared, right_geom)? {
+                left_indices.push(left_row as u32);
+                right_indices.push(right_row as u32);
+            }
+        }
+    }
+
+    Ok((left_indices.into(), right_indices.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{PointArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::indexed::array::IndexedGeometryArray;
+    use crate::trait_::ArrayAccessor;
+    use geo::{point, polygon, Contains};
+
+    #[test]
+    fn points_within_polygons() {
+        let points = vec![
+            point!(x: 0.5, y: 0.5),
+            point!(x: 1.5, y: 0.5),
+            point!(x: 5., y: 5.),
+            point!(x: 1.5, y: 1.5),
+        ];
+        let polygons = vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 1., y: 2.)],
+        ];
+        let point_arr: PointArray = (points.as_slice(), Dimension::XY).into();
+        let polygon_arr: PolygonArray = (polygons.as_slice(), Dimension::XY).into();
+
+        let (left, right) = sjoin_geos(&point_arr, &polygon_arr, SpatialPredicate::Within).unwrap();
+        let pairs = left
+            .values()
+            .iter()
+            .zip(right.values().iter())
+            .map(|(l, r)| (*l as usize, *r as usize))
+            .collect::<Vec<_>>();
+
+        // Compare against the native join over the geo-index R-trees of both arrays
+        let indexed_points = IndexedGeometryArray::new(point_arr);
+        let indexed_polygons = IndexedGeometryArray::new(polygon_arr);
+        let mut expected = indexed_points
+            .intersection_candidates_with_other(&indexed_polygons)
+            .filter(|(i, j)| {
+                let point = indexed_points.array.value_as_geo(*i);
+                let polygon = indexed_polygons.array.value_as_geo(*j);
+                polygon.contains(&point)
+            })
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+
+        assert_eq!(pairs, expected);
+        assert_eq!(pairs, vec![(0, 0), (1, 0), (1, 1), (3, 1)]);
+    }
+}
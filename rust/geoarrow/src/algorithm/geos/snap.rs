@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use geos::Geom;
+
+use crate::algorithm::geos::util::{as_geos_accessor, native_array_from_geos};
+use crate::error::{GeoArrowError, Result};
+use crate::{ArrayBase, NativeArray};
+
+/// Snap the vertices and segments of each geometry in `arr` to the vertices of the geometry in the
+/// same row of `reference`.
+///
+/// Vertices within `tolerance` of a reference vertex are moved onto it. Both arrays must have the
+/// same length. The output is null where either input is null.
+pub fn snap(
+    arr: &dyn NativeArray,
+    reference: &dyn NativeArray,
+    tolerance: f64,
+) -> Result<Arc<dyn NativeArray>> {
+    if arr.len() != reference.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            arr.len(),
+            reference.len()
+        )));
+    }
+
+    let accessor = as_geos_accessor(arr)?;
+    let reference = as_geos_accessor(reference)?;
+
+    let mut output = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let geom = match (accessor.get_as_geometry(i)?, reference.get_as_geometry(i)?) {
+            (Some(geom), Some(reference_geom)) => Some(geom.snap(&reference_geom, tolerance)?),
+            _ => None,
+        };
+        output.push(geom);
+    }
+
+    native_array_from_geos(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsNativeArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::point;
+
+    #[test]
+    fn snap_point_to_reference_vertex() {
+        let arr: PointArray = (vec![point!(x: 1.001, y: 1.)].as_slice(), Dimension::XY).into();
+        let reference: PointArray = (vec![point!(x: 1., y: 1.)].as_slice(), Dimension::XY).into();
+
+        let output = snap(&arr, &reference, 0.01).unwrap();
+        let snapped: geo::Point = output.as_ref().as_point().value(0).into();
+        assert_eq!(snapped, point!(x: 1., y: 1.));
+
+        // Outside of the tolerance the point is left unchanged
+        let output = snap(&arr, &reference, 0.0001).unwrap();
+        let snapped: geo::Point = output.as_ref().as_point().value(0).into();
+        assert_eq!(snapped, point!(x: 1.001, y: 1.));
+    }
+}
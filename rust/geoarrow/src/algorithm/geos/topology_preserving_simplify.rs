@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use geos::Geom;
+
+use crate::algorithm::geos::util::{as_geos_accessor, native_array_from_geos};
+use crate::error::Result;
+use crate::{ArrayBase, NativeArray};
+
+/// Simplify each geometry in `arr` while preserving its topology.
+///
+/// Unlike Douglas-Peucker simplification, which simplifies each ring independently and can
+/// produce self-intersections or holes outside of their shell, this guarantees that valid input
+/// polygons remain valid. Nulls are preserved.
+pub fn topology_preserving_simplify(
+    arr: &dyn NativeArray,
+    tolerance: f64,
+) -> Result<Arc<dyn NativeArray>> {
+    let accessor = as_geos_accessor(arr)?;
+
+    let mut output = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let geom = accessor
+            .get_as_geometry(i)?
+            .map(|geom| geom.topology_preserve_simplify(tolerance))
+            .transpose()?;
+        output.push(geom);
+    }
+
+    native_array_from_geos(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Simplify;
+    use crate::algorithm::geos::IsValid;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use geo::polygon;
+
+    #[test]
+    fn hole_stays_inside_shell() {
+        // Removing the top vertex of the exterior would leave the hole outside of the shell
+        let polygon = polygon!(
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 12.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 4., y: 10.5),
+                    (x: 6., y: 10.5),
+                    (x: 6., y: 11.),
+                    (x: 4., y: 11.),
+                    (x: 4., y: 10.5),
+                ],
+            ],
+        );
+        let arr: PolygonArray = (vec![Some(polygon), None], Dimension::XY).into();
+        let arr_ref: &dyn NativeArray = &arr;
+
+        let douglas_peucker = arr_ref.simplify(&3.).unwrap();
+        let valid = IsValid::is_valid(&douglas_peucker.as_ref()).unwrap();
+        assert!(!valid.value(0));
+
+        let output = topology_preserving_simplify(arr_ref, 3.).unwrap();
+        assert_eq!(output.len(), 2);
+        assert!(output.is_null(1));
+        let valid = IsValid::is_valid(&output.as_ref()).unwrap();
+        assert!(valid.value(0));
+    }
+}
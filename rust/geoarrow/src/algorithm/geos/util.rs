@@ -1,11 +1,18 @@
+use std::sync::Arc;
+
+use arrow_array::types::Float64Type;
 use arrow_array::{ArrowPrimitiveType, PrimitiveArray};
 use arrow_buffer::BufferBuilder;
 
-use crate::array::PolygonArray;
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::array::{GeometryCollectionArray, GeometryCollectionBuilder, PolygonArray};
 use crate::datatypes::Dimension;
-use crate::error::GeoArrowError;
-use crate::io::geos::scalar::GEOSPolygon;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::from_geo_vec;
+pub(super) use crate::io::geos::as_geos_accessor;
+use crate::io::geos::scalar::{GEOSGeometryCollection, GEOSPolygon};
 use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::NativeArray;
 
 // Note: This is derived from arrow-rs here:
 // https://github.com/apache/arrow-rs/blob/3ed7cc61d4157263ef2ab5c2d12bc7890a5315b3/arrow-array/src/array/primitive_array.rs#L806-L830
@@ -40,32 +47,63 @@ where
     Ok(PrimitiveArray::new(values, nulls))
 }
 
-pub(super) fn try_unary_polygon<'a, F>(
+/// Apply a GEOS operation that outputs polygons to each geometry, with a per-row parameter.
+///
+/// The output is null where either the input geometry or its parameter is null.
+pub(super) fn try_broadcast_polygon<'a, F>(
     array: &'a dyn NativeGEOSGeometryAccessor<'a>,
+    param: &BroadcastablePrimitive<Float64Type>,
     op: F,
     output_dim: Dimension,
 ) -> std::result::Result<PolygonArray, GeoArrowError>
 where
-    F: Fn(geos::Geometry) -> std::result::Result<geos::Geometry, geos::Error>,
+    F: Fn(geos::Geometry, f64) -> std::result::Result<geos::Geometry, geos::Error>,
 {
     let len = array.len();
+    param.check_len(len)?;
 
-    let mut buffer = vec![None; len];
+    let mut buffer = Vec::with_capacity(len);
 
     // Note: this assumes the output geometry is a polygon
-    let f = |idx| {
-        unsafe {
-            buffer[idx] = Some(GEOSPolygon::new_unchecked(op(
-                array.value_as_geometry_unchecked(idx)?
-            )?))
+    for (idx, param) in (0..len).zip(param) {
+        let geom = match (array.get_as_geometry(idx)?, param) {
+            (Some(geom), Some(param)) => Some(GEOSPolygon::new_unchecked(op(geom, param)?)),
+            _ => None,
         };
-        Ok::<_, geos::Error>(())
-    };
-
-    match array.nulls() {
-        Some(nulls) => nulls.try_for_each_valid_idx(f)?,
-        None => (0..len).try_for_each(f)?,
+        buffer.push(geom);
     }
 
     Ok(PolygonArray::from((buffer, output_dim)))
 }
+
+/// Build a [`GeometryCollectionArray`] from GEOS geometry collections, preserving nulls.
+pub(super) fn geometry_collection_from_geos(
+    geoms: Vec<Option<geos::Geometry>>,
+    output_dim: Dimension,
+) -> Result<GeometryCollectionArray> {
+    let geoms = geoms
+        .into_iter()
+        .map(|geom| geom.map(GEOSGeometryCollection::new_unchecked))
+        .collect::<Vec<_>>();
+    Ok(
+        GeometryCollectionBuilder::from_nullable_geometry_collections(
+            &geoms,
+            output_dim,
+            Default::default(),
+            Default::default(),
+            false,
+        )?
+        .finish(),
+    )
+}
+
+/// Build the narrowest native array that can hold the given GEOS geometries, preserving nulls.
+pub(super) fn native_array_from_geos(
+    geoms: Vec<Option<geos::Geometry>>,
+) -> Result<Arc<dyn NativeArray>> {
+    let geoms = geoms
+        .into_iter()
+        .map(|geom| geom.map(geo::Geometry::try_from).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    from_geo_vec(&geoms, Default::default(), false)
+}
@@ -0,0 +1,82 @@
+use geo::Rect;
+use geos::Geom;
+
+use crate::algorithm::geos::util::geometry_collection_from_geos;
+use crate::array::{GeometryCollectionArray, MultiPointArray};
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::ArrayBase;
+
+/// Compute the Voronoi diagram of the points in each multi point.
+///
+/// Each output row is a geometry collection with one polygon per distinct input point. If
+/// `envelope` is provided, the diagram is extended to cover it, and cells are clipped to it;
+/// otherwise GEOS uses an envelope slightly larger than the input extent.
+///
+/// Null input yields null output.
+pub fn voronoi(
+    points: &MultiPointArray,
+    envelope: Option<Rect>,
+) -> Result<GeometryCollectionArray> {
+    let envelope = envelope
+        .map(|rect| geos::Geometry::try_from(&rect.to_polygon()))
+        .transpose()?;
+
+    let mut output = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let cells = match points.get_as_geometry(i)? {
+            Some(geom) => {
+                let diagram = geom.voronoi(envelope.as_ref(), 0., false)?;
+                Some(match &envelope {
+                    Some(envelope) => clip_cells(&diagram, envelope)?,
+                    None => diagram,
+                })
+            }
+            None => None,
+        };
+        output.push(cells);
+    }
+
+    geometry_collection_from_geos(output, Dimension::XY)
+}
+
+/// Clip each cell of a Voronoi diagram to `envelope`.
+///
+/// Cells are clipped individually because GEOS overlay operations do not accept geometry
+/// collections as input.
+fn clip_cells(diagram: &geos::Geometry, envelope: &geos::Geometry) -> Result<geos::Geometry> {
+    let num_cells = diagram.get_num_geometries()?;
+    let mut cells = Vec::with_capacity(num_cells);
+    for i in 0..num_cells {
+        cells.push(diagram.get_geometry_n(i)?.intersection(envelope)?);
+    }
+    Ok(geos::Geometry::create_geometry_collection(cells)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+    use geo::{coord, point, MultiPoint};
+    use geo_traits::GeometryCollectionTrait;
+
+    #[test]
+    fn voronoi_four_points() {
+        let multi_point = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 2., y: 0.),
+            point!(x: 0., y: 2.),
+            point!(x: 2., y: 2.),
+        ]);
+        let arr: MultiPointArray = (vec![Some(multi_point), None], Dimension::XY).into();
+
+        let output = voronoi(&arr, None).unwrap();
+        assert_eq!(output.value(0).num_geometries(), 4);
+        assert!(output.is_null(1));
+
+        let envelope = Rect::new(coord! { x: -1., y: -1. }, coord! { x: 3., y: 3. });
+        let output = voronoi(&arr, Some(envelope)).unwrap();
+        assert_eq!(output.value(0).num_geometries(), 4);
+    }
+}
@@ -0,0 +1,188 @@
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::array::{AsNativeArray, PolygonArray, WKBArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::io::wkb::to_wkb;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Check whether two arrays hold the same geometries, up to a tolerance of `epsilon` on each
+/// coordinate value.
+///
+/// Geometries are compared by their structure (geometry type, dimension, and number of parts and
+/// coordinates), which must match exactly, and their coordinates, which may differ by at most
+/// `epsilon` in each of x, y, z, and m. As with [`geometry_hash`][super::geometry_hash], the
+/// comparison does not depend on the coordinate type or the type of array that holds the
+/// geometries, and rects are compared as the equivalent polygon.
+///
+/// Returns `false` if the arrays have different lengths or nulls in different rows.
+pub fn approx_eq(left: &dyn NativeArray, right: &dyn NativeArray, epsilon: f64) -> Result<bool> {
+    if left.len() != right.len() {
+        return Ok(false);
+    }
+
+    let left = wkb_array(left);
+    let right = wkb_array(right);
+    for (left, right) in left.iter().zip(right.iter()) {
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                if !geometry_approx_eq(&left.parse()?, &right.parse()?, epsilon) {
+                    return Ok(false);
+                }
+            }
+            (None, None) => (),
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+fn wkb_array(arr: &dyn NativeArray) -> WKBArray<i32> {
+    match arr.data_type() {
+        NativeType::Rect(_) => (&PolygonArray::from(arr.as_rect().clone())).into(),
+        _ => to_wkb(arr),
+    }
+}
+
+fn coord_approx_eq(
+    left: &impl CoordTrait<T = f64>,
+    right: &impl CoordTrait<T = f64>,
+    epsilon: f64,
+) -> bool {
+    let dim = left.dim();
+    dim == right.dim()
+        && (0..dim.size()).all(|i| (left.nth_or_panic(i) - right.nth_or_panic(i)).abs() <= epsilon)
+}
+
+fn point_approx_eq(
+    left: &impl PointTrait<T = f64>,
+    right: &impl PointTrait<T = f64>,
+    epsilon: f64,
+) -> bool {
+    match (left.coord(), right.coord()) {
+        (Some(left), Some(right)) => coord_approx_eq(&left, &right, epsilon),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn line_string_approx_eq(
+    left: &impl LineStringTrait<T = f64>,
+    right: &impl LineStringTrait<T = f64>,
+    epsilon: f64,
+) -> bool {
+    left.num_coords() == right.num_coords()
+        && left
+            .coords()
+            .zip(right.coords())
+            .all(|(left, right)| coord_approx_eq(&left, &right, epsilon))
+}
+
+fn polygon_approx_eq(
+    left: &impl PolygonTrait<T = f64>,
+    right: &impl PolygonTrait<T = f64>,
+    epsilon: f64,
+) -> bool {
+    let exterior_eq = match (left.exterior(), right.exterior()) {
+        (Some(left), Some(right)) => line_string_approx_eq(&left, &right, epsilon),
+        (None, None) => true,
+        _ => false,
+    };
+    exterior_eq
+        && left.num_interiors() == right.num_interiors()
+        && left
+            .interiors()
+            .zip(right.interiors())
+            .all(|(left, right)| line_string_approx_eq(&left, &right, epsilon))
+}
+
+fn geometry_approx_eq(
+    left: &impl GeometryTrait<T = f64>,
+    right: &impl GeometryTrait<T = f64>,
+    epsilon: f64,
+) -> bool {
+    if left.dim() != right.dim() {
+        return false;
+    }
+
+    match (left.as_type(), right.as_type()) {
+        (GeometryType::Point(l), GeometryType::Point(r)) => point_approx_eq(l, r, epsilon),
+        (GeometryType::LineString(l), GeometryType::LineString(r)) => {
+            line_string_approx_eq(l, r, epsilon)
+        }
+        (GeometryType::Polygon(l), GeometryType::Polygon(r)) => polygon_approx_eq(l, r, epsilon),
+        (GeometryType::MultiPoint(l), GeometryType::MultiPoint(r)) => {
+            l.num_points() == r.num_points()
+                && l.points()
+                    .zip(r.points())
+                    .all(|(l, r)| point_approx_eq(&l, &r, epsilon))
+        }
+        (GeometryType::MultiLineString(l), GeometryType::MultiLineString(r)) => {
+            l.num_line_strings() == r.num_line_strings()
+                && l.line_strings()
+                    .zip(r.line_strings())
+                    .all(|(l, r)| line_string_approx_eq(&l, &r, epsilon))
+        }
+        (GeometryType::MultiPolygon(l), GeometryType::MultiPolygon(r)) => {
+            l.num_polygons() == r.num_polygons()
+                && l.polygons()
+                    .zip(r.polygons())
+                    .all(|(l, r)| polygon_approx_eq(&l, &r, epsilon))
+        }
+        (GeometryType::GeometryCollection(l), GeometryType::GeometryCollection(r)) => {
+            l.num_geometries() == r.num_geometries()
+                && l.geometries()
+                    .zip(r.geometries())
+                    .all(|(l, r)| geometry_approx_eq(&l, &r, epsilon))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, MixedGeometryArray};
+    use crate::datatypes::Dimension;
+    use geo::line_string;
+
+    #[test]
+    fn within_epsilon() {
+        let left: LineStringArray = (
+            vec![line_string![(x: 1., y: 2.), (x: 3., y: 4.)]].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let right: LineStringArray = (
+            vec![line_string![(x: 1. + 1e-12, y: 2.), (x: 3., y: 4. - 1e-12)]].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+
+        assert_ne!(left, right);
+        assert!(approx_eq(&left, &right, 1e-9).unwrap());
+        assert!(!approx_eq(&left, &right, 1e-15).unwrap());
+
+        let mixed = MixedGeometryArray::from(right);
+        assert!(approx_eq(&left, &mixed, 1e-9).unwrap());
+    }
+
+    #[test]
+    fn different_structure() {
+        let left: LineStringArray = (
+            vec![line_string![(x: 1., y: 2.), (x: 3., y: 4.)]].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let right: LineStringArray = (
+            vec![line_string![(x: 1., y: 2.), (x: 3., y: 4.), (x: 5., y: 6.)]].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        assert!(!approx_eq(&left, &right, 1e-9).unwrap());
+    }
+}
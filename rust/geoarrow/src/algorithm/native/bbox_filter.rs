@@ -0,0 +1,95 @@
+use crate::algorithm::native::bounding_rect::BoundingRect;
+
+/// A 2D bounding box predicate for filtering geometries by their extent.
+///
+/// All comparisons are inclusive, so boxes and points that only touch the edge of the filter are
+/// considered to match. This is shared across readers that support bounding box pushdown so that
+/// they apply the same intersection semantics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BboxFilter {
+    pub minx: f64,
+    pub miny: f64,
+    pub maxx: f64,
+    pub maxy: f64,
+}
+
+impl BboxFilter {
+    /// Construct a new filter from its bounds.
+    pub fn new(minx: f64, miny: f64, maxx: f64, maxy: f64) -> Self {
+        Self {
+            minx,
+            miny,
+            maxx,
+            maxy,
+        }
+    }
+
+    /// Returns `true` if `other` intersects this filter, including touching its edges.
+    pub fn intersects_rect(&self, other: &BoundingRect) -> bool {
+        self.intersects_bounds(other.minx(), other.miny(), other.maxx(), other.maxy())
+    }
+
+    /// Returns `true` if the box described by the given bounds intersects this filter, including
+    /// touching its edges.
+    pub fn intersects_bounds(&self, minx: f64, miny: f64, maxx: f64, maxy: f64) -> bool {
+        minx <= self.maxx && maxx >= self.minx && miny <= self.maxy && maxy >= self.miny
+    }
+
+    /// Returns `true` if the point `(x, y)` lies within this filter or on its boundary.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        x >= self.minx && x <= self.maxx && y >= self.miny && y <= self.maxy
+    }
+}
+
+impl From<geo::Rect> for BboxFilter {
+    fn from(value: geo::Rect) -> Self {
+        Self::new(value.min().x, value.min().y, value.max().x, value.max().y)
+    }
+}
+
+impl From<BoundingRect> for BboxFilter {
+    fn from(value: BoundingRect) -> Self {
+        Self::new(value.minx(), value.miny(), value.maxx(), value.maxy())
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for BboxFilter {
+    fn from(value: (f64, f64, f64, f64)) -> Self {
+        Self::new(value.0, value.1, value.2, value.3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rect(minx: f64, miny: f64, maxx: f64, maxy: f64) -> BoundingRect {
+        let mut rect = BoundingRect::new();
+        rect.add_coord(&geo::coord! { x: minx, y: miny });
+        rect.add_coord(&geo::coord! { x: maxx, y: maxy });
+        rect
+    }
+
+    #[test]
+    fn intersects_edge_touching() {
+        let filter = BboxFilter::new(0., 0., 10., 10.);
+        assert!(filter.intersects_rect(&rect(10., 0., 20., 10.)));
+        assert!(filter.intersects_rect(&rect(10., 10., 20., 20.)));
+        assert!(filter.intersects_rect(&rect(2., 2., 3., 3.)));
+    }
+
+    #[test]
+    fn intersects_disjoint() {
+        let filter = BboxFilter::new(0., 0., 10., 10.);
+        assert!(!filter.intersects_rect(&rect(10.5, 0., 20., 10.)));
+        assert!(!filter.intersects_rect(&rect(0., -5., 10., -0.1)));
+    }
+
+    #[test]
+    fn contains_point() {
+        let filter = BboxFilter::new(0., 0., 10., 10.);
+        assert!(filter.contains_point(5., 5.));
+        assert!(filter.contains_point(10., 0.));
+        assert!(!filter.contains_point(10.1, 5.));
+    }
+}
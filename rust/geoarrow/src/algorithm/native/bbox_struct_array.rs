@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field};
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::array::AsNativeArray;
+use crate::datatypes::NativeType;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Compute the bounding box of each row in `$arr`, using the `BoundingRect` method
+/// `$func` to add each geometry.
+macro_rules! row_bounds {
+    ($arr:expr, $func:ident) => {
+        $arr.iter()
+            .map(|maybe_g| {
+                maybe_g.map(|g| {
+                    let mut bounds = BoundingRect::new();
+                    bounds.$func(&g);
+                    bounds
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+}
+
+/// Compute the bounding box of each geometry as an Arrow `StructArray`.
+///
+/// The output has four non-nullable `Float64` fields, `xmin`, `ymin`, `xmax`, and `ymax`, which is
+/// the layout of a GeoParquet 1.1 bounding box covering column. Null geometries produce a null
+/// struct.
+pub fn bbox_struct_array(arr: &dyn NativeArray) -> StructArray {
+    use NativeType::*;
+
+    let bounds = match arr.data_type() {
+        Point(_, _) => row_bounds!(arr.as_point(), add_point),
+        LineString(_, _) => row_bounds!(arr.as_line_string(), add_line_string),
+        Polygon(_, _) => row_bounds!(arr.as_polygon(), add_polygon),
+        MultiPoint(_, _) => row_bounds!(arr.as_multi_point(), add_multi_point),
+        MultiLineString(_, _) => row_bounds!(arr.as_multi_line_string(), add_multi_line_string),
+        MultiPolygon(_, _) => row_bounds!(arr.as_multi_polygon(), add_multi_polygon),
+        Mixed(_, _) => row_bounds!(arr.as_mixed(), add_geometry),
+        GeometryCollection(_, _) => {
+            row_bounds!(arr.as_geometry_collection(), add_geometry_collection)
+        }
+        Rect(_) => row_bounds!(arr.as_rect(), add_rect),
+    };
+
+    let mut xmin = Vec::with_capacity(bounds.len());
+    let mut ymin = Vec::with_capacity(bounds.len());
+    let mut xmax = Vec::with_capacity(bounds.len());
+    let mut ymax = Vec::with_capacity(bounds.len());
+    for rect in bounds.iter() {
+        // Null rows still need a value in each child array
+        let rect = rect.unwrap_or_default();
+        xmin.push(rect.minx());
+        ymin.push(rect.miny());
+        xmax.push(rect.maxx());
+        ymax.push(rect.maxy());
+    }
+
+    let fields = ["xmin", "ymin", "xmax", "ymax"]
+        .into_iter()
+        .map(|name| Field::new(name, DataType::Float64, false))
+        .collect::<Vec<_>>();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Float64Array::from(xmin)),
+        Arc::new(Float64Array::from(ymin)),
+        Arc::new(Float64Array::from(xmax)),
+        Arc::new(Float64Array::from(ymax)),
+    ];
+    let validity = NullBuffer::from_iter(bounds.iter().map(Option::is_some));
+    let validity = (validity.null_count() > 0).then_some(validity);
+
+    StructArray::new(fields.into(), columns, validity)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use crate::test::polygon;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::Array;
+
+    #[test]
+    fn two_polygons() {
+        let polygons = vec![
+            polygon::p0(),
+            geo::polygon![
+                (x: 0., y: 1.),
+                (x: 2., y: 1.),
+                (x: 2., y: 5.),
+                (x: 0., y: 1.),
+            ],
+        ];
+        let arr: PolygonArray = (polygons.as_slice(), Dimension::XY).into();
+        let bbox = bbox_struct_array(&arr);
+
+        assert_eq!(bbox.len(), 2);
+        assert_eq!(bbox.null_count(), 0);
+        let values = |name: &str| {
+            bbox.column_by_name(name)
+                .unwrap()
+                .as_primitive::<Float64Type>()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(values("xmin"), vec![-111., 0.]);
+        assert_eq!(values("ymin"), vec![41., 1.]);
+        assert_eq!(values("xmax"), vec![-104., 2.]);
+        assert_eq!(values("ymax"), vec![45., 5.]);
+    }
+}
@@ -143,6 +143,7 @@ impl Cast for MultiLineStringArray {
 
         match to_type {
             LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
+            MultiLineString(_, _) => Ok(Arc::new(array)),
             Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
             dt => Err(GeoArrowError::General(format!(
@@ -162,6 +163,7 @@ impl Cast for MultiPolygonArray {
 
         match to_type {
             Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
+            MultiPolygon(_, _) => Ok(Arc::new(array)),
             Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
             dt => Err(GeoArrowError::General(format!(
@@ -219,6 +221,18 @@ impl Cast for GeometryCollectionArray {
     }
 }
 
+impl Cast for RectArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn cast(&self, to_type: &NativeType) -> Self::Output {
+        match to_type {
+            NativeType::Rect(_) => Ok(Arc::new(self.clone())),
+            // Every other cast goes through the equivalent polygon array
+            _ => PolygonArray::from(self.clone()).cast(to_type),
+        }
+    }
+}
+
 impl Cast for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
@@ -228,6 +242,13 @@ impl Cast for &dyn NativeArray {
         //     return Ok(Arc::new(self.to_owned()));
         // }
 
+        if self.dimension() != to_type.dimension() {
+            return Err(GeoArrowError::DimensionMismatch {
+                expected: to_type.dimension(),
+                found: self.dimension(),
+            });
+        }
+
         use NativeType::*;
 
         match self.data_type() {
@@ -239,11 +260,23 @@ impl Cast for &dyn NativeArray {
             MultiPolygon(_, _) => self.as_ref().as_multi_polygon().cast(to_type),
             Mixed(_, _) => self.as_ref().as_mixed().cast(to_type),
             GeometryCollection(_, _) => self.as_ref().as_geometry_collection().cast(to_type),
-            _ => todo!(),
+            Rect(_) => self.as_ref().as_rect().cast(to_type),
         }
     }
 }
 
+/// Cast a geometry array to another native geometry type.
+///
+/// Casts operate directly on the underlying buffers and never round-trip through WKB. Single
+/// geometry types can always be promoted to their multi counterpart, to
+/// [`MixedGeometryArray`], or to [`GeometryCollectionArray`]. Multi, mixed, and geometry
+/// collection arrays can be downcast to a narrower type only if every geometry fits, e.g. a
+/// [`MultiPolygonArray`] where each row has at most one polygon can be cast to a
+/// [`PolygonArray`]. Any other cast, such as from `Polygon` to `Point`, returns an error.
+pub fn cast(arr: &dyn NativeArray, to_type: &NativeType) -> Result<Arc<dyn NativeArray>> {
+    arr.cast(to_type)
+}
+
 macro_rules! impl_chunked_cast_non_generic {
     ($chunked_array:ty) => {
         impl Cast for $chunked_array {
@@ -330,3 +363,63 @@ impl_chunked_cast_generic!(ChunkedMultiLineStringArray);
 impl_chunked_cast_generic!(ChunkedMultiPolygonArray);
 impl_chunked_cast_generic!(ChunkedMixedGeometryArray);
 impl_chunked_cast_generic!(ChunkedGeometryCollectionArray);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::{multipolygon, point, polygon};
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn point_to_multi_point() {
+        let arr = point::point_array();
+        let to_type = NativeType::MultiPoint(arr.coord_type(), Dimension::XY);
+        let output = cast(&arr, &to_type).unwrap();
+        assert_eq!(output.data_type(), to_type);
+
+        let output = output.as_ref().as_multi_point();
+        assert_eq!(output.len(), arr.len());
+        for (multi_point, point) in output.iter_geo().zip(arr.iter_geo()) {
+            let multi_point = multi_point.unwrap();
+            assert_eq!(multi_point.0.len(), 1);
+            assert_eq!(multi_point.0[0], point.unwrap());
+        }
+    }
+
+    #[test]
+    fn multi_polygon_to_polygon() {
+        let arr = polygon::p_array();
+        let to_type = NativeType::Polygon(arr.coord_type(), Dimension::XY);
+
+        // Each row has a single polygon
+        let multi_polygons = MultiPolygonArray::from(arr.clone());
+        let output = cast(&multi_polygons, &to_type).unwrap();
+        assert_eq!(output.as_ref().as_polygon(), &arr);
+
+        // The first row has two polygons
+        let multi_polygons = multipolygon::mp_array();
+        assert!(cast(&multi_polygons, &to_type).is_err());
+    }
+
+    #[test]
+    fn invalid_cast() {
+        let arr = polygon::p_array();
+        let to_type = NativeType::Point(arr.coord_type(), Dimension::XY);
+        assert!(cast(&arr, &to_type).is_err());
+    }
+
+    #[test]
+    fn dimension_cast() {
+        let arr = point::point_z_array();
+        let to_type = NativeType::MultiPoint(arr.coord_type(), Dimension::XY);
+        assert!(matches!(
+            cast(&arr, &to_type),
+            Err(GeoArrowError::DimensionMismatch {
+                expected: Dimension::XY,
+                found: Dimension::XYZ
+            })
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::{type_counts, TotalBounds};
+use crate::chunked_array::ChunkedNativeArray;
+use crate::datatypes::{Dimension, GeometryType};
+
+/// Summary statistics of a geometry column, as needed to write GeoParquet `geo` metadata.
+#[derive(Debug, Clone)]
+pub struct GeoColumnStatistics {
+    bbox: BoundingRect,
+    geometry_types: HashSet<GeometryType>,
+    has_z: bool,
+}
+
+impl GeoColumnStatistics {
+    /// The bounding box of all geometries in the column.
+    pub fn bbox(&self) -> &BoundingRect {
+        &self.bbox
+    }
+
+    /// The types of the non-null geometries present in the column.
+    pub fn geometry_types(&self) -> &HashSet<GeometryType> {
+        &self.geometry_types
+    }
+
+    /// Whether any non-null geometry in the column has a Z dimension.
+    pub fn has_z(&self) -> bool {
+        self.has_z
+    }
+}
+
+/// Compute the [`GeoColumnStatistics`] of a chunked geometry column, aggregating over all chunks.
+///
+/// The geometry types are computed with [`type_counts`], so a mixed geometry column lists each
+/// type it contains, while the members of geometry collections are not inspected.
+pub fn column_statistics(arr: &dyn ChunkedNativeArray) -> GeoColumnStatistics {
+    let bbox = arr.total_bounds();
+
+    let mut geometry_types = HashSet::new();
+    let mut has_z = false;
+    for chunk in arr.geometry_chunks() {
+        for type_and_dim in type_counts(chunk.as_ref()).into_keys() {
+            geometry_types.insert(type_and_dim.geometry_type);
+            has_z |= type_and_dim.dim == Dimension::XYZ;
+        }
+    }
+
+    GeoColumnStatistics {
+        bbox,
+        geometry_types,
+        has_z,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::MixedGeometryArray;
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::{point, polygon};
+
+    #[test]
+    fn mixed_column_statistics() {
+        let chunk1: MixedGeometryArray = (
+            vec![Some(geo::Geometry::Point(point::p0())), None],
+            Dimension::XY,
+        )
+            .try_into()
+            .unwrap();
+        let chunk2: MixedGeometryArray = (
+            vec![Some(geo::Geometry::Polygon(polygon::p0()))],
+            Dimension::XY,
+        )
+            .try_into()
+            .unwrap();
+        let chunked = ChunkedGeometryArray::new(vec![chunk1, chunk2]);
+
+        let stats = column_statistics(&chunked);
+        assert_eq!(
+            stats.geometry_types(),
+            &HashSet::from([GeometryType::Point, GeometryType::Polygon])
+        );
+        assert!(!stats.has_z());
+
+        let expected_bbox = (&chunked as &dyn ChunkedNativeArray).total_bounds();
+        assert_eq!(stats.bbox().minx(), expected_bbox.minx());
+        assert_eq!(stats.bbox().maxy(), expected_bbox.maxy());
+    }
+}
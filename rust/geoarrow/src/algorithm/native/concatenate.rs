@@ -1,10 +1,11 @@
-use std::collections::HashSet;
+use std::sync::Arc;
 
 use crate::array::*;
 use crate::chunked_array::*;
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 
 pub trait Concatenate: Sized {
     type Output;
@@ -16,7 +17,7 @@ impl Concatenate for &[PointArray] {
     type Output = Result<PointArray>;
 
     fn concatenate(&self) -> Self::Output {
-        let common_dimension = infer_common_dimension(self.iter().map(|arr| arr.dimension()));
+        let common_dimension = infer_common_dimension(self.iter().map(|arr| arr.dimension()))?;
 
         let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
         let mut builder = PointBuilder::with_capacity(common_dimension, output_capacity);
@@ -33,11 +34,15 @@ macro_rules! impl_concatenate {
 
             fn concatenate(&self) -> Self::Output {
                 let common_dimension =
-                    infer_common_dimension(self.iter().map(|arr| arr.dimension()));
+                    infer_common_dimension(self.iter().map(|arr| arr.dimension()))?;
 
                 let output_capacity = self.iter().fold(<$capacity>::new_empty(), |sum, val| {
                     sum + val.buffer_lengths()
                 });
+                check_i32_offsets(
+                    output_capacity.coord_capacity(),
+                    self.iter().map(|arr| arr.len()).sum(),
+                )?;
                 let mut builder = <$builder>::with_capacity(common_dimension, output_capacity);
                 for chunk in self.iter() {
                     for geom in chunk.iter() {
@@ -88,10 +93,30 @@ impl_concatenate!(
     push_geometry_collection
 );
 
-fn infer_common_dimension(dimensions: impl Iterator<Item = Dimension>) -> Dimension {
-    let dimensions: HashSet<Dimension> = HashSet::from_iter(dimensions);
-    assert_eq!(dimensions.len(), 1);
-    dimensions.into_iter().next().unwrap()
+fn infer_common_dimension(mut dimensions: impl Iterator<Item = Dimension>) -> Result<Dimension> {
+    let expected = dimensions.next().ok_or_else(|| {
+        GeoArrowError::General("Cannot concatenate an empty list of arrays".to_string())
+    })?;
+    for found in dimensions {
+        if found != expected {
+            return Err(GeoArrowError::DimensionMismatch { expected, found });
+        }
+    }
+    Ok(expected)
+}
+
+/// Check that the combined arrays can be indexed by `i32` offsets.
+///
+/// Every offset buffer of a concatenated array is bounded by either its number of coordinates or
+/// its number of geometries.
+fn check_i32_offsets(num_coords: usize, num_geoms: usize) -> Result<()> {
+    let max_offset = i32::MAX as usize;
+    if num_coords > max_offset || num_geoms > max_offset {
+        return Err(GeoArrowError::General(format!(
+            "Cannot concatenate {num_geoms} geometries with {num_coords} coordinates into an array with i32 offsets"
+        )));
+    }
+    Ok(())
 }
 
 impl Concatenate for ChunkedPointArray {
@@ -121,3 +146,64 @@ impl_chunked_concatenate!(ChunkedMultiLineStringArray, MultiLineStringArray);
 impl_chunked_concatenate!(ChunkedMultiPolygonArray, MultiPolygonArray);
 impl_chunked_concatenate!(ChunkedMixedGeometryArray, MixedGeometryArray);
 impl_chunked_concatenate!(ChunkedGeometryCollectionArray, GeometryCollectionArray);
+
+impl Concatenate for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn concatenate(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().concatenate()?),
+            LineString(_, _) => Arc::new(self.as_line_string().concatenate()?),
+            Polygon(_, _) => Arc::new(self.as_polygon().concatenate()?),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().concatenate()?),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().concatenate()?),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().concatenate()?),
+            Mixed(_, _) => Arc::new(self.as_mixed().concatenate()?),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().concatenate()?),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point};
+
+    #[test]
+    fn concatenate_dimension_mismatch() {
+        let arrays = vec![point::point_array(), point::point_z_array()];
+        let err = arrays.as_slice().concatenate().unwrap_err();
+        assert!(matches!(
+            err,
+            GeoArrowError::DimensionMismatch {
+                expected: Dimension::XY,
+                found: Dimension::XYZ
+            }
+        ));
+    }
+
+    #[test]
+    fn into_single() {
+        let chunk1: LineStringArray = (
+            vec![linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let chunk2: LineStringArray = (
+            vec![linestring::ls1(), linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let chunked = ChunkedGeometryArray::new(vec![chunk1, chunk2]);
+
+        let single = chunked.into_single().unwrap();
+        assert_eq!(single.len(), 5);
+        let single = single.as_ref().as_line_string();
+        assert_eq!(single.value_as_geo(0), linestring::ls0());
+        assert_eq!(single.value_as_geo(4), linestring::ls1());
+    }
+}
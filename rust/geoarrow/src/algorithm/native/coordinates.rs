@@ -0,0 +1,97 @@
+use arrow_array::{Float64Array, UInt32Array};
+use geo_traits::CoordTrait;
+
+use crate::algorithm::native::extract_coords;
+use crate::datatypes::Dimension;
+use crate::NativeArray;
+
+/// Flatten the coordinates of every geometry in `arr` into plain Arrow arrays.
+///
+/// Returns the x, y, and, for 3D input, z values of all coordinates, in the order they are
+/// stored, along with geometry offsets: the coordinates of row `i` are those in
+/// `offsets[i]..offsets[i + 1]`, so the offsets have one more value than `arr` has rows. Null and
+/// empty geometries have no coordinates. Rects contribute their four corners in 2D.
+pub fn coordinates(
+    arr: &dyn NativeArray,
+) -> (
+    Float64Array,
+    Float64Array,
+    Option<Float64Array>,
+    UInt32Array,
+) {
+    let vertices = extract_coords(arr, false);
+    let coords = &vertices.coords;
+
+    let mut x = Vec::with_capacity(coords.len());
+    let mut y = Vec::with_capacity(coords.len());
+    let mut z = (coords.dim() == Dimension::XYZ).then(|| Vec::with_capacity(coords.len()));
+    for i in 0..coords.len() {
+        let coord = coords.value(i);
+        x.push(coord.x());
+        y.push(coord.y());
+        if let Some(z) = z.as_mut() {
+            z.push(coord.nth_or_panic(2));
+        }
+    }
+
+    let offsets = vertices
+        .geom_offsets
+        .iter()
+        .map(|offset| *offset as u32)
+        .collect::<Vec<_>>();
+    (
+        x.into(),
+        y.into(),
+        z.map(Float64Array::from),
+        offsets.into(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{CoordType, LineStringArray};
+    use crate::test::point;
+    use geo::line_string;
+
+    #[test]
+    fn line_strings() {
+        let arr: LineStringArray = (
+            vec![
+                Some(line_string![(x: 0., y: 1.), (x: 2., y: 3.)]),
+                None,
+                Some(line_string![(x: 4., y: 5.), (x: 6., y: 7.), (x: 8., y: 9.)]),
+            ],
+            Dimension::XY,
+        )
+            .into();
+
+        let (x, y, z, offsets) = coordinates(&arr);
+        assert_eq!(x, Float64Array::from(vec![0., 2., 4., 6., 8.]));
+        assert_eq!(y, Float64Array::from(vec![1., 3., 5., 7., 9.]));
+        assert!(z.is_none());
+        assert_eq!(offsets, UInt32Array::from(vec![0, 2, 2, 5]));
+    }
+
+    #[test]
+    fn points_z_interleaved() {
+        let arr = point::point_z_array().into_coord_type(CoordType::Interleaved);
+
+        let (x, y, z, offsets) = coordinates(&arr);
+        assert_eq!(x, Float64Array::from(vec![0., 3., 6.]));
+        assert_eq!(y, Float64Array::from(vec![1., 4., 7.]));
+        assert_eq!(z, Some(Float64Array::from(vec![2., 5., 8.])));
+        assert_eq!(offsets, UInt32Array::from(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn points_z_separated() {
+        let arr = point::point_z_array().into_coord_type(CoordType::Separated);
+
+        let (x, y, z, offsets) = coordinates(&arr);
+        assert_eq!(x, Float64Array::from(vec![0., 3., 6.]));
+        assert_eq!(y, Float64Array::from(vec![1., 4., 7.]));
+        assert_eq!(z, Some(Float64Array::from(vec![2., 5., 8.])));
+        assert_eq!(offsets, UInt32Array::from(vec![0, 1, 2, 3]));
+    }
+}
@@ -430,6 +430,8 @@ fn resolve_types(types: &HashSet<NativeType>) -> NativeType {
     } else if types.len() == 1 {
         *types.iter().next().unwrap()
     } else if types.len() == 2 {
+        // All chunks of a chunked array share a dimension
+        let dim = types.iter().next().unwrap().dimension();
         let mut extension_name_set = HashSet::new();
         // let mut coord_types = HashSet::new();
         types.iter().for_each(|t| {
@@ -438,22 +440,23 @@ fn resolve_types(types: &HashSet<NativeType>) -> NativeType {
         if extension_name_set.contains("geoarrow.point")
             && extension_name_set.contains("geoarrow.multipoint")
         {
-            NativeType::MultiPoint(Default::default(), Dimension::XY)
+            NativeType::MultiPoint(Default::default(), dim)
         } else if extension_name_set.contains("geoarrow.linestring")
             && extension_name_set.contains("geoarrow.multilinestring")
         {
-            NativeType::MultiLineString(Default::default(), Dimension::XY)
+            NativeType::MultiLineString(Default::default(), dim)
         } else if extension_name_set.contains("geoarrow.polygon")
             && extension_name_set.contains("geoarrow.multipolygon")
         {
-            NativeType::MultiPolygon(Default::default(), Dimension::XY)
+            NativeType::MultiPolygon(Default::default(), dim)
         } else if extension_name_set.contains("geoarrow.geometrycollection") {
-            NativeType::GeometryCollection(Default::default(), Dimension::XY)
+            NativeType::GeometryCollection(Default::default(), dim)
         } else {
-            NativeType::Mixed(Default::default(), Dimension::XY)
+            NativeType::Mixed(Default::default(), dim)
         }
     } else {
-        NativeType::Mixed(Default::default(), Dimension::XY)
+        let dim = types.iter().next().unwrap().dimension();
+        NativeType::Mixed(Default::default(), dim)
     }
 }
 
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use arrow_array::UInt32Array;
+
+use crate::algorithm::geo::HasDimensions;
+use crate::algorithm::native::Take;
+use crate::error::Result;
+use crate::NativeArray;
+
+/// Remove empty and null geometries from `arr`.
+///
+/// Returns the compacted array along with the index in `arr` of each kept row, which can be used
+/// to [`take`][arrow::compute::take] the matching rows of other columns. Emptiness is determined
+/// by [`HasDimensions::is_empty`].
+pub fn drop_empty(arr: &dyn NativeArray) -> Result<(Arc<dyn NativeArray>, UInt32Array)> {
+    let is_empty = HasDimensions::is_empty(&arr)?;
+    let indices = is_empty
+        .iter()
+        .enumerate()
+        .filter_map(|(i, is_empty)| (is_empty == Some(false)).then_some(i as u32))
+        .collect::<UInt32Array>();
+    let output = arr.take(&indices)?;
+    Ok((output, indices))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsNativeArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::test::polygon::{p0, p1};
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn empty_and_null() {
+        let empty = geo::Polygon::new(geo::LineString::new(vec![]), vec![]);
+        let arr: PolygonArray = (
+            vec![Some(p0()), Some(empty), None, Some(p1())],
+            Dimension::XY,
+        )
+            .into();
+
+        let (output, indices) = drop_empty(&arr).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 3]));
+        assert_eq!(output.len(), 2);
+        assert_eq!(output.null_count(), 0);
+
+        let output = output.as_ref().as_polygon();
+        assert_eq!(output.value_as_geo(0), p0());
+        assert_eq!(output.value_as_geo(1), p1());
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+
+use crate::algorithm::geo::utils::as_geometry_accessor;
+use crate::algorithm::native::rebuild::SimpleCoord;
+use crate::array::*;
+use crate::datatypes::{Dimension, NativeType};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Extract the vertices of each geometry in `arr` as a multi point.
+///
+/// Vertices are returned in the order they are stored. If `dedup` is `true`, only the first
+/// occurrence of each distinct vertex is kept within a row, which for example drops the closing
+/// vertex of each polygon ring. Z and M values are preserved.
+///
+/// Null input yields null output.
+pub fn extract_coords(arr: &dyn NativeArray, dedup: bool) -> MultiPointArray {
+    let dim = match arr.data_type() {
+        // Rect corners are extracted in 2D
+        NativeType::Rect(_) => Dimension::XY,
+        _ => arr.dimension(),
+    };
+    let mut builder = MultiPointBuilder::new_with_options(dim, arr.coord_type(), arr.metadata());
+
+    let mut push_coords = |maybe_coords: Option<Vec<SimpleCoord>>| {
+        if let Some(mut coords) = maybe_coords {
+            if dedup {
+                dedup_coords(&mut coords);
+            }
+            for coord in coords.iter() {
+                unsafe { builder.push_coord(coord).unwrap() };
+            }
+            builder.try_push_length(coords.len()).unwrap();
+        } else {
+            builder.push_null();
+        }
+    };
+
+    if let NativeType::Rect(_) = arr.data_type() {
+        arr.as_rect().iter().for_each(|maybe_rect| {
+            push_coords(maybe_rect.map(|rect| {
+                let mut coords = vec![];
+                add_rect_coords(&rect, &mut coords);
+                coords
+            }))
+        });
+    } else {
+        let accessor = as_geometry_accessor(arr).unwrap();
+        for i in 0..accessor.len() {
+            push_coords(accessor.get_as_geometry(i).map(|geom| {
+                let mut coords = vec![];
+                add_geometry_coords(&geom, &mut coords);
+                coords
+            }));
+        }
+    }
+
+    builder.finish()
+}
+
+/// Remove repeated coordinates, keeping the first occurrence of each.
+fn dedup_coords(coords: &mut Vec<SimpleCoord>) {
+    let mut seen = HashSet::with_capacity(coords.len());
+    coords.retain(|coord| {
+        seen.insert((
+            coord.x.to_bits(),
+            coord.y.to_bits(),
+            coord.z.map(|z| z.to_bits()),
+        ))
+    });
+}
+
+fn add_line_string_coords(line_string: &impl LineStringTrait<T = f64>, out: &mut Vec<SimpleCoord>) {
+    out.extend(
+        line_string
+            .coords()
+            .map(|coord| SimpleCoord::from_coord(&coord)),
+    );
+}
+
+fn add_polygon_coords(polygon: &impl PolygonTrait<T = f64>, out: &mut Vec<SimpleCoord>) {
+    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+        add_line_string_coords(&ring, out);
+    }
+}
+
+fn add_rect_coords(rect: &impl RectTrait<T = f64>, out: &mut Vec<SimpleCoord>) {
+    let (min, max) = (rect.min(), rect.max());
+    let corner = |x, y| SimpleCoord {
+        x,
+        y,
+        z: None,
+        dim: geo_traits::Dimensions::Xy,
+    };
+    out.extend([
+        corner(min.x(), min.y()),
+        corner(max.x(), min.y()),
+        corner(max.x(), max.y()),
+        corner(min.x(), max.y()),
+    ]);
+}
+
+fn add_geometry_coords(geom: &impl GeometryTrait<T = f64>, out: &mut Vec<SimpleCoord>) {
+    match geom.as_type() {
+        GeometryType::Point(point) => {
+            if let Some(coord) = point.coord() {
+                out.push(SimpleCoord::from_coord(&coord));
+            }
+        }
+        GeometryType::LineString(line_string) => add_line_string_coords(line_string, out),
+        GeometryType::Polygon(polygon) => add_polygon_coords(polygon, out),
+        GeometryType::MultiPoint(multi_point) => {
+            for point in multi_point.points() {
+                if let Some(coord) = point.coord() {
+                    out.push(SimpleCoord::from_coord(&coord));
+                }
+            }
+        }
+        GeometryType::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string.line_strings() {
+                add_line_string_coords(&line_string, out);
+            }
+        }
+        GeometryType::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon.polygons() {
+                add_polygon_coords(&polygon, out);
+            }
+        }
+        GeometryType::GeometryCollection(geometry_collection) => {
+            for geometry in geometry_collection.geometries() {
+                add_geometry_coords(&geometry, out);
+            }
+        }
+        GeometryType::Rect(rect) => add_rect_coords(rect, out),
+        GeometryType::Triangle(_) | GeometryType::Line(_) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::polygon;
+
+    #[test]
+    fn triangle_dedup() {
+        let triangle = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 0., y: 1.),
+        ];
+        let arr: PolygonArray = (vec![Some(triangle), None], Dimension::XY).into();
+
+        let output = extract_coords(&arr, false);
+        assert_eq!(output.value(0).num_points(), 4);
+
+        let output = extract_coords(&arr, true);
+        let multi_point: geo::MultiPoint = output.value(0).into();
+        assert_eq!(
+            multi_point,
+            geo::MultiPoint::from(vec![(0., 0.), (1., 0.), (0., 1.)])
+        );
+        assert!(output.is_null(1));
+    }
+}
@@ -0,0 +1,70 @@
+use arrow_array::UInt64Array;
+
+use crate::array::{AsNativeArray, PolygonArray, WKBArray};
+use crate::datatypes::NativeType;
+use crate::io::wkb::to_wkb;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a, which unlike [`std::hash::DefaultHasher`] is stable across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Compute a stable 64-bit hash of each geometry in `arr`, for use as a cache key.
+///
+/// The hash is computed from the little-endian ISO WKB encoding of each geometry. It therefore
+/// only depends on the geometry type, dimension, and coordinates of a geometry, and not on the
+/// coordinate type or the type of array that holds it. For example, the same point in an
+/// interleaved [`PointArray`][crate::array::PointArray], a separated `PointArray`, and a
+/// [`MixedGeometryArray`][crate::array::MixedGeometryArray] has the same hash. Rects are hashed as
+/// the equivalent polygon.
+///
+/// The hash is stable across releases and platforms, but is not cryptographically secure. Null
+/// rows have a null hash.
+pub fn geometry_hash(arr: &dyn NativeArray) -> UInt64Array {
+    let wkb_arr: WKBArray<i32> = match arr.data_type() {
+        NativeType::Rect(_) => (&PolygonArray::from(arr.as_rect().clone())).into(),
+        _ => to_wkb(arr),
+    };
+    wkb_arr
+        .iter()
+        .map(|maybe_wkb| maybe_wkb.map(|wkb| fnv1a(wkb.as_ref())))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{CoordType, MixedGeometryArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::test::point;
+    use arrow_array::Array;
+
+    #[test]
+    fn hash_independent_of_coord_type() {
+        let interleaved = point::point_array().to_coord_type(CoordType::Interleaved);
+        let separated = point::point_array().to_coord_type(CoordType::Separated);
+
+        let interleaved_hash = geometry_hash(&interleaved);
+        let separated_hash = geometry_hash(&separated);
+        assert_eq!(interleaved_hash, separated_hash);
+        assert_ne!(interleaved_hash.value(0), interleaved_hash.value(1));
+
+        let mixed = MixedGeometryArray::from(separated);
+        assert_eq!(geometry_hash(&mixed), interleaved_hash);
+    }
+
+    #[test]
+    fn null_hash() {
+        let arr: PointArray = (vec![Some(point::p0()), None], Dimension::XY).into();
+        let hash = geometry_hash(&arr);
+        assert!(hash.is_valid(0));
+        assert!(hash.is_null(1));
+    }
+}
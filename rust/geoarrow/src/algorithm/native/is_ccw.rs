@@ -0,0 +1,66 @@
+use arrow_array::BooleanArray;
+use geo_traits::{CoordTrait, LineStringTrait, PolygonTrait};
+
+use crate::algorithm::native::Unary;
+use crate::array::PolygonArray;
+
+/// Twice the signed area of a ring, computed with the shoelace formula.
+///
+/// The result is positive when the ring is wound counter-clockwise.
+fn ring_signed_area_2x(ring: &impl LineStringTrait<T = f64>) -> f64 {
+    let num_coords = ring.num_coords();
+    if num_coords < 3 {
+        return 0.;
+    }
+
+    let mut sum = 0.;
+    let mut previous = ring.coord(num_coords - 1).unwrap().x_y();
+    for coord in ring.coords() {
+        let current = coord.x_y();
+        sum += previous.0 * current.1 - current.0 * previous.1;
+        previous = current;
+    }
+    sum
+}
+
+/// Returns `true` for each polygon whose exterior ring is wound counter-clockwise.
+///
+/// This is the case when the exterior ring has a positive signed area. Empty polygons and
+/// degenerate rings with zero area return `false`. Null polygons produce null values.
+pub fn is_ccw(arr: &PolygonArray) -> BooleanArray {
+    arr.unary_boolean(|polygon| {
+        polygon
+            .exterior()
+            .is_some_and(|exterior| ring_signed_area_2x(&exterior) > 0.)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use arrow_array::Array;
+    use geo::polygon;
+
+    #[test]
+    fn cw_and_ccw() {
+        let ccw = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let cw = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 0.),
+        ];
+        let arr: PolygonArray = (vec![Some(ccw), Some(cw), None], Dimension::XY).into();
+
+        let result = is_ccw(&arr);
+        assert!(result.value(0));
+        assert!(!result.value(1));
+        assert!(result.is_null(2));
+    }
+}
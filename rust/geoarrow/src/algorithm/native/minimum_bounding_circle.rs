@@ -0,0 +1,171 @@
+use arrow_array::Float64Array;
+use geo::{coord, Coord};
+use geo_traits::{CoordTrait, MultiPointTrait, PointTrait};
+
+use crate::algorithm::native::extract_coords;
+use crate::array::PointArray;
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Compute the minimum enclosing circle of each geometry in `arr`, as its center and radius.
+///
+/// The circle is the smallest one containing every vertex of the geometry, and therefore the
+/// whole geometry. It is computed in 2D with Welzl's algorithm, in expected linear time in the
+/// number of vertices. A single point yields a circle with a radius of zero.
+///
+/// Null or empty input yields a null center and a null radius.
+pub fn minimum_bounding_circle(arr: &dyn NativeArray) -> Result<(PointArray, Float64Array)> {
+    let vertices = extract_coords(arr, true);
+
+    let mut centers = Vec::with_capacity(vertices.len());
+    let mut radii = Vec::with_capacity(vertices.len());
+    for maybe_multi_point in vertices.iter() {
+        let circle = maybe_multi_point.and_then(|multi_point| {
+            let coords = multi_point
+                .points()
+                .filter_map(|point| point.coord().map(|c| coord! { x: c.x(), y: c.y() }))
+                .collect::<Vec<_>>();
+            enclosing_circle(coords)
+        });
+        centers.push(circle.map(|circle| geo::Point(circle.center)));
+        radii.push(circle.map(|circle| circle.radius));
+    }
+
+    Ok(((centers, Dimension::XY).into(), radii.into()))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circle {
+    center: Coord,
+    radius: f64,
+}
+
+impl Circle {
+    fn from_two(a: Coord, b: Coord) -> Self {
+        let center = (a + b) / 2.;
+        Self {
+            center,
+            radius: distance(center, a),
+        }
+    }
+
+    /// The circumscribed circle of a triangle, or the circle of its longest side if the
+    /// triangle is degenerate.
+    fn from_three(a: Coord, b: Coord, c: Coord) -> Self {
+        let (b_rel, c_rel) = (b - a, c - a);
+        let d = 2. * (b_rel.x * c_rel.y - b_rel.y * c_rel.x);
+        if d == 0. {
+            return [
+                Self::from_two(a, b),
+                Self::from_two(a, c),
+                Self::from_two(b, c),
+            ]
+            .into_iter()
+            .max_by(|l, r| l.radius.total_cmp(&r.radius))
+            .unwrap();
+        }
+
+        let b_sq = b_rel.x * b_rel.x + b_rel.y * b_rel.y;
+        let c_sq = c_rel.x * c_rel.x + c_rel.y * c_rel.y;
+        let center = a + coord! {
+            x: (c_rel.y * b_sq - b_rel.y * c_sq) / d,
+            y: (b_rel.x * c_sq - c_rel.x * b_sq) / d,
+        };
+        Self {
+            center,
+            radius: distance(center, a),
+        }
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        // Allow for rounding error in the computed center and radius
+        distance(self.center, coord) <= self.radius * (1. + 1e-12) + f64::EPSILON
+    }
+}
+
+fn distance(a: Coord, b: Coord) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Welzl's algorithm in its iterative form, on a pseudo-randomly shuffled copy of the input.
+fn enclosing_circle(mut coords: Vec<Coord>) -> Option<Circle> {
+    shuffle(&mut coords);
+
+    let mut circle = Circle {
+        center: *coords.first()?,
+        radius: 0.,
+    };
+    for (i, &p) in coords.iter().enumerate().skip(1) {
+        if circle.contains(p) {
+            continue;
+        }
+        circle = Circle {
+            center: p,
+            radius: 0.,
+        };
+        for (j, &q) in coords[..i].iter().enumerate() {
+            if circle.contains(q) {
+                continue;
+            }
+            circle = Circle::from_two(p, q);
+            for &r in &coords[..j] {
+                if !circle.contains(r) {
+                    circle = Circle::from_three(p, q, r);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+/// Shuffle with a fixed-seed xorshift generator, so that the output is deterministic while
+/// sorted input still takes expected linear time.
+fn shuffle(coords: &mut [Coord]) {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..coords.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        coords.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MultiPointArray, PolygonArray};
+    use crate::ArrayBase;
+    use arrow_array::Array;
+    use geo::{point, polygon};
+
+    #[test]
+    fn two_points() {
+        let multi_point = geo::MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 6., y: 8.)]);
+        let arr: MultiPointArray = (vec![Some(multi_point), None], Dimension::XY).into();
+
+        let (centers, radii) = minimum_bounding_circle(&arr).unwrap();
+        assert_eq!(centers.value_as_geo(0), point!(x: 3., y: 4.));
+        // The diameter is the distance between the two points
+        assert_eq!(radii.value(0) * 2., 10.);
+        assert!(ArrayBase::is_null(&centers, 1));
+        assert!(radii.is_null(1));
+    }
+
+    #[test]
+    fn square() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let arr: PolygonArray = (vec![square].as_slice(), Dimension::XY).into();
+
+        let (centers, radii) = minimum_bounding_circle(&arr).unwrap();
+        let center = centers.value_as_geo(0);
+        assert!((center.x() - 1.).abs() < 1e-12 && (center.y() - 1.).abs() < 1e-12);
+        assert!((radii.value(0) - 2f64.sqrt()).abs() < 1e-12);
+    }
+}
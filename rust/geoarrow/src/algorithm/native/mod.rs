@@ -3,30 +3,73 @@
 //! Where possible, operations on scalars are implemented in terms of [geometry
 //! traits](../../geo_traits).
 
+mod approx_eq;
+mod bbox_filter;
+mod bbox_struct_array;
 mod binary;
 pub mod bounding_rect;
 mod cast;
+mod column_statistics;
 mod concatenate;
+mod coordinates;
 pub(crate) mod downcast;
+mod drop_empty;
 pub(crate) mod eq;
 mod explode;
+mod extract_coords;
+mod geometry_hash;
+mod is_ccw;
 mod map_chunks;
 mod map_coords;
+mod minimum_bounding_circle;
+mod owned_slice;
+mod par_map_geo;
+mod point_mean;
+pub(crate) mod rebuild;
 mod rechunk;
+mod segmentize;
+mod set_precision;
+mod split_antimeridian;
+mod swap_xy;
 mod take;
+mod to_coord_list;
 mod total_bounds;
+mod type_counts;
 pub(crate) mod type_id;
 mod unary;
+mod unique;
+mod windows;
 
+pub use approx_eq::approx_eq;
+pub use bbox_filter::BboxFilter;
+pub use bbox_struct_array::bbox_struct_array;
 pub use binary::Binary;
-pub use cast::Cast;
+pub use cast::{cast, Cast};
+pub use column_statistics::{column_statistics, GeoColumnStatistics};
 pub use concatenate::Concatenate;
+pub use coordinates::coordinates;
 pub use downcast::{Downcast, DowncastTable};
+pub use drop_empty::drop_empty;
 pub use explode::{Explode, ExplodeTable};
+pub use extract_coords::extract_coords;
+pub use geometry_hash::geometry_hash;
+pub use is_ccw::is_ccw;
 pub use map_chunks::MapChunks;
 pub use map_coords::MapCoords;
+pub use minimum_bounding_circle::minimum_bounding_circle;
+pub use owned_slice::{owned_slice, shrink_to_fit};
+pub use par_map_geo::par_map_geo;
+pub use point_mean::point_mean;
 pub use rechunk::Rechunk;
+pub use segmentize::{segmentize, Segmentize};
+pub use set_precision::{set_precision, SetPrecision};
+pub use split_antimeridian::split_antimeridian;
+pub use swap_xy::{swap_xy, SwapXY};
 pub use take::Take;
+pub use to_coord_list::to_coord_list;
 pub use total_bounds::TotalBounds;
+pub use type_counts::type_counts;
 pub use type_id::TypeIds;
 pub use unary::{Unary, UnaryPoint};
+pub use unique::unique;
+pub use windows::windows;
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use arrow_array::new_empty_array;
+
+use crate::array::NativeArrayDyn;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+
+/// Copy a slice of `arr` into a new, compacted array.
+///
+/// Unlike [`NativeArray::slice`], which is a zero-copy view onto the parent buffers, the output
+/// only contains the coordinates referenced by the selected geometries and its offsets start from
+/// zero. This allows the parent array's buffers to be dropped.
+///
+/// # Errors
+///
+/// - If `offset + length` exceeds the length of the array.
+/// - If `length` is zero.
+pub fn owned_slice(
+    arr: &dyn NativeArray,
+    offset: usize,
+    length: usize,
+) -> Result<Arc<dyn NativeArray>> {
+    if offset + length > arr.len() {
+        return Err(GeoArrowError::General(format!(
+            "offset + length may not exceed length of array, got {} + {} and {}",
+            offset,
+            length,
+            arr.len()
+        )));
+    }
+    if length == 0 {
+        return Err(GeoArrowError::General(
+            "length must be at least 1".to_string(),
+        ));
+    }
+    Ok(arr.owned_slice(offset, length))
+}
+
+/// Copy `arr` into a new array whose buffers are exactly as large as the data they hold.
+///
+/// Arrays built with over-reserved capacity, or sliced from a larger array, keep their entire
+/// original allocations alive. This reallocates the coordinate, offset, and validity buffers of
+/// the full array so that any unused capacity is released.
+pub fn shrink_to_fit(arr: &dyn NativeArray) -> Arc<dyn NativeArray> {
+    if arr.is_empty() {
+        let empty = new_empty_array(&arr.storage_type());
+        return NativeArrayDyn::from_arrow_array(&empty, &arr.extension_field())
+            .expect("empty array has the same type as the input array")
+            .into_inner();
+    }
+    arr.owned_slice(0, arr.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::*;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, multipolygon, point};
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use geo::GeometryCollection;
+
+    #[test]
+    fn owned_slice_compacts_coords() {
+        let arr = linestring::ls_array();
+        let sliced = owned_slice(&arr, 1, 1).unwrap();
+        let sliced = sliced.as_ref().as_line_string();
+        assert_eq!(sliced.len(), 1);
+        assert_eq!(sliced.coords.len(), 2);
+        assert_eq!(arr.coords.len(), 4);
+        assert_eq!(sliced.value_as_geo(0), linestring::ls1());
+        assert!(!sliced.coords.shares_buffer_with(&arr.coords));
+
+        // A zero-copy slice keeps pointing at the original coordinates
+        let zero_copy = arr.slice(1, 1);
+        assert!(zero_copy.coords.shares_buffer_with(&arr.coords));
+    }
+
+    #[test]
+    fn owned_slice_multi_polygon() {
+        let arr = multipolygon::mp_array();
+        let sliced = owned_slice(&arr, 1, 1).unwrap();
+        let sliced = sliced.as_ref().as_multi_polygon();
+        assert_eq!(sliced.value_as_geo(0), multipolygon::mp1());
+        assert!(sliced.coords.len() < arr.coords.len());
+    }
+
+    #[test]
+    fn owned_slice_geometry_collection() {
+        let geoms = vec![
+            GeometryCollection::new_from(vec![point::p0().into(), linestring::ls0().into()]),
+            GeometryCollection::new_from(vec![point::p1().into(), point::p2().into()]),
+            GeometryCollection::new_from(vec![]),
+        ];
+        let arr = GeometryCollectionBuilder::from_geometry_collections(
+            &geoms,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        let sliced = owned_slice(&arr, 1, 2).unwrap();
+        let sliced = sliced.as_ref().as_geometry_collection();
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.array.len(), 2);
+        assert_eq!(sliced.value_as_geo(0), geoms[1]);
+        assert_eq!(sliced.value_as_geo(1), geoms[2]);
+    }
+
+    #[test]
+    fn owned_slice_out_of_bounds() {
+        let arr = linestring::ls_array();
+        assert!(owned_slice(&arr, 1, 2).is_err());
+        assert!(owned_slice(&arr, 0, 0).is_err());
+    }
+
+    #[test]
+    fn shrink_to_fit_releases_capacity() {
+        let mut builder =
+            LineStringBuilder::with_capacity(Dimension::XY, LineStringCapacity::new(10_000, 1_000));
+        builder.push_line_string(Some(&linestring::ls0())).unwrap();
+        builder.push_line_string(Some(&linestring::ls1())).unwrap();
+        let arr = builder.finish();
+
+        let shrunk = shrink_to_fit(&arr);
+        let shrunk_ref = shrunk.as_ref().as_line_string();
+        assert_eq!(shrunk_ref, &arr);
+        assert_eq!(shrunk_ref.num_bytes(), arr.num_bytes());
+
+        let allocated = |arr: &dyn NativeArray| arr.to_array_ref().get_buffer_memory_size();
+        assert!(allocated(shrunk.as_ref()) < allocated(&arr));
+    }
+
+    #[test]
+    fn shrink_to_fit_empty() {
+        let arr = linestring::ls_array().slice(0, 0);
+        let shrunk = shrink_to_fit(&arr);
+        assert!(shrunk.is_empty());
+        assert_eq!(shrunk.data_type(), arr.data_type());
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{ArrayAccessor, GeometryArrayBuilder};
+use crate::NativeArray;
+
+/// Map each non-null geometry of `arr` through `op` and collect the results into a new builder.
+///
+/// `op` is called with the index of each row and its geometry converted to a [`geo`] type, and
+/// may return `None` to make that row null. Null rows are passed through as null without calling
+/// `op`. The output builder has the same dimension, coordinate type, and metadata as the input.
+///
+/// If the `rayon` feature is enabled, rows are transformed in parallel. Otherwise they are
+/// transformed serially. The output is the same either way.
+///
+/// # Errors
+///
+/// - If `arr` is not 2D, since [`geo`] types cannot hold Z or M values.
+pub fn par_map_geo<'a, A, B, F, G>(arr: &'a A, op: F) -> Result<B>
+where
+    A: ArrayAccessor<'a> + NativeArray + Sync,
+    A::ItemGeo: Send,
+    F: Fn(usize, A::ItemGeo) -> Option<G> + Sync + Send,
+    G: Into<geo::Geometry> + Send,
+    B: GeometryArrayBuilder,
+{
+    if arr.dimension() != Dimension::XY {
+        return Err(GeoArrowError::DimensionMismatch {
+            expected: Dimension::XY,
+            found: arr.dimension(),
+        });
+    }
+
+    let map_row = |i: usize| {
+        arr.get_as_geo(i)
+            .and_then(|geom| op(i, geom))
+            .map(Into::into)
+    };
+
+    #[cfg(feature = "rayon")]
+    let geoms = (0..arr.len())
+        .into_par_iter()
+        .map(map_row)
+        .collect::<Vec<_>>();
+
+    #[cfg(not(feature = "rayon"))]
+    let geoms = (0..arr.len()).map(map_row).collect::<Vec<_>>();
+
+    let mut builder = B::with_geom_capacity_and_options(
+        arr.dimension(),
+        geoms.len(),
+        arr.coord_type(),
+        arr.metadata(),
+    );
+    for geom in geoms.iter() {
+        builder.push_geometry(geom.as_ref())?;
+    }
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointBuilder;
+    use crate::test::point;
+
+    #[test]
+    fn par_map_geo_rejects_3d() {
+        let arr = point::point_z_array();
+        let result: Result<PointBuilder> = par_map_geo(&arr, |_, geom| Some(geom));
+        assert!(matches!(
+            result,
+            Err(GeoArrowError::DimensionMismatch {
+                expected: Dimension::XY,
+                found: Dimension::XYZ
+            })
+        ));
+    }
+}
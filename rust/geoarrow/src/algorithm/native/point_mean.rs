@@ -0,0 +1,90 @@
+use geo_traits::{MultiPointTrait, PointTrait};
+
+use crate::algorithm::native::extract_coords;
+use crate::algorithm::native::rebuild::SimpleCoord;
+use crate::array::*;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Compute the unweighted mean of the vertices of each geometry in `arr`.
+///
+/// Every stored vertex counts equally, including the closing vertex of each polygon ring. This
+/// differs from [`Centroid`][crate::algorithm::geo::Centroid], which weights polygons by area and
+/// line strings by length. Z and M values are averaged as well.
+///
+/// Null and empty geometries yield null output.
+pub fn point_mean(arr: &dyn NativeArray) -> PointArray {
+    let coords = extract_coords(arr, false);
+    let mut builder = PointBuilder::with_capacity_and_options(
+        coords.dimension(),
+        coords.len(),
+        arr.coord_type(),
+        arr.metadata(),
+    );
+
+    for maybe_multi_point in coords.iter() {
+        let mean = maybe_multi_point.and_then(|multi_point| {
+            let n = multi_point.num_points();
+            if n == 0 {
+                return None;
+            }
+
+            let (mut x, mut y, mut z) = (0., 0., None);
+            let mut dim = geo_traits::Dimensions::Xy;
+            for coord in multi_point.points().filter_map(|point| point.coord()) {
+                let coord = SimpleCoord::from_coord(&coord);
+                dim = coord.dim;
+                x += coord.x;
+                y += coord.y;
+                if let Some(coord_z) = coord.z {
+                    z = Some(z.unwrap_or(0.) + coord_z);
+                }
+            }
+            Some(SimpleCoord {
+                x: x / n as f64,
+                y: y / n as f64,
+                z: z.map(|z: f64| z / n as f64),
+                dim,
+            })
+        });
+        builder.push_coord(mean.as_ref());
+    }
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Centroid;
+    use crate::datatypes::Dimension;
+    use geo::{point, polygon, MultiPolygon};
+
+    #[test]
+    fn point_mean_differs_from_centroid() {
+        // A large square and a small triangle far away from it. The centroid is dominated by the
+        // square's area, while the vertex mean weighs the triangle's vertices equally.
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+            ],
+            polygon![
+                (x: 10., y: 0.),
+                (x: 11., y: 0.),
+                (x: 10., y: 1.),
+            ],
+        ]);
+        let arr: MultiPolygonArray = (vec![Some(multi_polygon), None], Dimension::XY).into();
+
+        let mean = point_mean(&arr);
+        let centroid = arr.centroid();
+
+        // 5 square vertices summing to (8, 8) and 4 triangle vertices summing to (41, 1)
+        assert_eq!(mean.value_as_geo(0), point!(x: 49. / 9., y: 1.));
+        assert_ne!(mean.value_as_geo(0), centroid.value_as_geo(0));
+        assert!(mean.is_null(1));
+    }
+}
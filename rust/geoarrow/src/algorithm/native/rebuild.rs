@@ -0,0 +1,232 @@
+//! Helpers for rebuilding arrays from transformed coordinate sequences.
+//!
+//! Unlike [`MapCoords`][crate::algorithm::native::MapCoords], these preserve the dimension of the
+//! input array and allow the number of coordinates in each sequence to change.
+
+use geo_traits::{
+    CoordTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait,
+    PointTrait, PolygonTrait,
+};
+
+use crate::array::*;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// A minimal owned coordinate with an optional third ordinate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SimpleCoord {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    /// The third ordinate, which is Z or M depending on `dim`.
+    pub(crate) z: Option<f64>,
+    pub(crate) dim: geo_traits::Dimensions,
+}
+
+impl SimpleCoord {
+    pub(crate) fn from_coord(coord: &impl CoordTrait<T = f64>) -> Self {
+        Self {
+            x: coord.x(),
+            y: coord.y(),
+            z: coord.nth(2),
+            dim: coord.dim(),
+        }
+    }
+}
+
+impl CoordTrait for SimpleCoord {
+    type T = f64;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        match n {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z.expect("n out of range"),
+            _ => panic!("n out of range"),
+        }
+    }
+
+    fn x(&self) -> Self::T {
+        self.x
+    }
+
+    fn y(&self) -> Self::T {
+        self.y
+    }
+}
+
+fn collect_coords(line_string: &impl LineStringTrait<T = f64>) -> Vec<SimpleCoord> {
+    line_string
+        .coords()
+        .map(|coord| SimpleCoord::from_coord(&coord))
+        .collect()
+}
+
+/// Rebuild an array by transforming each of its coordinate sequences.
+///
+/// A coordinate sequence is a line string, a polygon ring, or the points of a multi point. The
+/// output array has the same geometry type, dimension, coordinate type, and metadata as the input.
+pub(crate) trait MapCoordSequences: Sized {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>;
+}
+
+impl MapCoordSequences for LineStringArray {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>,
+    {
+        let mut builder = LineStringBuilder::new_with_options(
+            self.dimension(),
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for maybe_line_string in self.iter() {
+            if let Some(line_string) = maybe_line_string {
+                let coords = op(collect_coords(&line_string));
+                for coord in coords.iter() {
+                    unsafe { builder.push_coord(coord)? };
+                }
+                builder.try_push_length(coords.len())?;
+            } else {
+                builder.push_null();
+            }
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl MapCoordSequences for PolygonArray {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>,
+    {
+        let mut builder =
+            PolygonBuilder::new_with_options(self.dimension(), self.coord_type(), self.metadata());
+
+        for maybe_polygon in self.iter() {
+            match maybe_polygon {
+                Some(polygon) if polygon.exterior().is_some() => {
+                    unsafe { builder.try_push_geom_offset(polygon.num_interiors() + 1)? };
+                    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+                        let coords = op(collect_coords(&ring));
+                        unsafe { builder.try_push_ring_offset(coords.len())? };
+                        for coord in coords.iter() {
+                            unsafe { builder.push_coord(coord)? };
+                        }
+                    }
+                }
+                // Empty polygons are passed through unchanged
+                Some(polygon) => builder.push_polygon(Some(&polygon))?,
+                None => builder.push_null(),
+            }
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl MapCoordSequences for MultiPointArray {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>,
+    {
+        let mut builder = MultiPointBuilder::new_with_options(
+            self.dimension(),
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for maybe_multi_point in self.iter() {
+            if let Some(multi_point) = maybe_multi_point {
+                let coords = op(multi_point
+                    .points()
+                    .filter_map(|point| point.coord().map(|coord| SimpleCoord::from_coord(&coord)))
+                    .collect());
+                for coord in coords.iter() {
+                    unsafe { builder.push_coord(coord)? };
+                }
+                builder.try_push_length(coords.len())?;
+            } else {
+                builder.push_null();
+            }
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl MapCoordSequences for MultiLineStringArray {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>,
+    {
+        let mut builder = MultiLineStringBuilder::new_with_options(
+            self.dimension(),
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for maybe_multi_line_string in self.iter() {
+            if let Some(multi_line_string) = maybe_multi_line_string {
+                unsafe { builder.try_push_geom_offset(multi_line_string.num_line_strings())? };
+                for line_string in multi_line_string.line_strings() {
+                    let coords = op(collect_coords(&line_string));
+                    unsafe { builder.try_push_ring_offset(coords.len())? };
+                    for coord in coords.iter() {
+                        unsafe { builder.push_coord(coord)? };
+                    }
+                }
+            } else {
+                builder.push_null();
+            }
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl MapCoordSequences for MultiPolygonArray {
+    fn map_coord_sequences<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(Vec<SimpleCoord>) -> Vec<SimpleCoord>,
+    {
+        let mut builder = MultiPolygonBuilder::new_with_options(
+            self.dimension(),
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for maybe_multi_polygon in self.iter() {
+            if let Some(multi_polygon) = maybe_multi_polygon {
+                unsafe { builder.try_push_geom_offset(multi_polygon.num_polygons())? };
+                for polygon in multi_polygon.polygons() {
+                    let num_rings = if polygon.exterior().is_some() {
+                        polygon.num_interiors() + 1
+                    } else {
+                        0
+                    };
+                    unsafe { builder.try_push_polygon_offset(num_rings)? };
+                    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+                        let coords = op(collect_coords(&ring));
+                        unsafe { builder.try_push_ring_offset(coords.len())? };
+                        for coord in coords.iter() {
+                            unsafe { builder.push_coord(coord)? };
+                        }
+                    }
+                }
+            } else {
+                builder.push_null();
+            }
+        }
+
+        Ok(builder.finish())
+    }
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use crate::algorithm::native::rebuild::{MapCoordSequences, SimpleCoord};
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+
+/// Subdivide the edges of geometries so that no segment is longer than `max_length`.
+///
+/// Segment lengths are measured in the XY plane. Vertices inserted along an edge are spaced evenly
+/// and their Z or M values, if any, are linearly interpolated between the edge's endpoints. The
+/// dimension of the input is preserved in the output.
+pub trait Segmentize {
+    type Output;
+
+    fn segmentize(&self, max_length: f64) -> Self::Output;
+}
+
+/// Subdivide the edges of each geometry in `arr` so that no segment exceeds `max_length`.
+///
+/// See [`Segmentize`] for details.
+pub fn segmentize(arr: &dyn NativeArray, max_length: f64) -> Result<Arc<dyn NativeArray>> {
+    arr.segmentize(max_length)
+}
+
+/// Linearly interpolate between `start` and `end`, where `fraction` is in `[0, 1]`.
+fn interpolate(start: &SimpleCoord, end: &SimpleCoord, fraction: f64) -> SimpleCoord {
+    let z = match (start.z, end.z) {
+        (Some(start_z), Some(end_z)) => Some(start_z + (end_z - start_z) * fraction),
+        _ => None,
+    };
+    SimpleCoord {
+        x: start.x + (end.x - start.x) * fraction,
+        y: start.y + (end.y - start.y) * fraction,
+        z,
+        dim: start.dim,
+    }
+}
+
+fn check_max_length(max_length: f64) -> Result<()> {
+    if max_length > 0.0 && max_length.is_finite() {
+        Ok(())
+    } else {
+        Err(GeoArrowError::General(format!(
+            "max_length must be a positive, finite number, got {max_length}"
+        )))
+    }
+}
+
+/// Segmentize the coordinates of a single line string or ring.
+fn segmentize_coords(coords: Vec<SimpleCoord>, max_length: f64) -> Vec<SimpleCoord> {
+    let mut output = Vec::with_capacity(coords.len());
+    let mut previous: Option<SimpleCoord> = None;
+    for current in coords {
+        if let Some(previous) = previous {
+            let length = (current.x - previous.x).hypot(current.y - previous.y);
+            if length > max_length {
+                let num_segments = (length / max_length).ceil() as usize;
+                for i in 1..num_segments {
+                    output.push(interpolate(
+                        &previous,
+                        &current,
+                        i as f64 / num_segments as f64,
+                    ));
+                }
+            }
+        }
+        output.push(current);
+        previous = Some(current);
+    }
+    output
+}
+
+impl Segmentize for PointArray {
+    type Output = Result<PointArray>;
+
+    fn segmentize(&self, max_length: f64) -> Self::Output {
+        check_max_length(max_length)?;
+        Ok(self.clone())
+    }
+}
+
+impl Segmentize for MultiPointArray {
+    type Output = Result<MultiPointArray>;
+
+    fn segmentize(&self, max_length: f64) -> Self::Output {
+        check_max_length(max_length)?;
+        Ok(self.clone())
+    }
+}
+
+macro_rules! impl_segmentize {
+    ($type:ty) => {
+        impl Segmentize for $type {
+            type Output = Result<$type>;
+
+            fn segmentize(&self, max_length: f64) -> Self::Output {
+                check_max_length(max_length)?;
+                self.map_coord_sequences(|coords| segmentize_coords(coords, max_length))
+            }
+        }
+    };
+}
+
+impl_segmentize!(LineStringArray);
+impl_segmentize!(PolygonArray);
+impl_segmentize!(MultiLineStringArray);
+impl_segmentize!(MultiPolygonArray);
+
+impl Segmentize for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn segmentize(&self, max_length: f64) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().segmentize(max_length)?),
+            LineString(_, _) => Arc::new(self.as_line_string().segmentize(max_length)?),
+            Polygon(_, _) => Arc::new(self.as_polygon().segmentize(max_length)?),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().segmentize(max_length)?),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().segmentize(max_length)?),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().segmentize(max_length)?),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use geo_traits::LineStringTrait;
+
+    fn coord(x: f64, y: f64, third: f64, dim: geo_traits::Dimensions) -> SimpleCoord {
+        SimpleCoord {
+            x,
+            y,
+            z: Some(third),
+            dim,
+        }
+    }
+
+    /// Segmentize a single line string with a third ordinate and check that the inserted vertices
+    /// interpolate it.
+    fn check_segmentize_3d(dim: Dimension) {
+        let coord_dim = dim.into();
+        let input_coords = [
+            coord(0., 0., 0., coord_dim),
+            coord(4., 0., 8., coord_dim),
+            coord(4., 1., 8., coord_dim),
+        ];
+        let mut builder = LineStringBuilder::new(dim);
+        for coord in input_coords.iter() {
+            unsafe { builder.push_coord(coord).unwrap() };
+        }
+        builder.try_push_length(input_coords.len()).unwrap();
+        builder.push_null();
+        let arr = builder.finish();
+
+        let output = segmentize(&arr, 1.5).unwrap();
+        assert_eq!(output.dimension(), dim);
+
+        let output = output.as_ref().as_line_string();
+        assert!(output.is_null(1));
+
+        // The first edge has length 4, so it is split into 3 segments. The second edge has length
+        // 1 and is left as is.
+        let line_string = output.value(0);
+        let coords = line_string
+            .coords()
+            .map(|coord| SimpleCoord::from_coord(&coord))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            coord(0., 0., 0., coord_dim),
+            coord(4. / 3., 0., 8. / 3., coord_dim),
+            coord(8. / 3., 0., 16. / 3., coord_dim),
+            coord(4., 0., 8., coord_dim),
+            coord(4., 1., 8., coord_dim),
+        ];
+        assert_eq!(coords.len(), expected.len());
+        for (actual, expected) in coords.iter().zip(expected.iter()) {
+            assert_eq!(actual.dim, expected.dim);
+            assert!((actual.x - expected.x).abs() < 1e-12);
+            assert!((actual.y - expected.y).abs() < 1e-12);
+            assert!((actual.z.unwrap() - expected.z.unwrap()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn segmentize_line_string_z() {
+        check_segmentize_3d(Dimension::XYZ);
+    }
+
+    #[test]
+    fn segmentize_line_string_m() {
+        check_segmentize_3d(Dimension::XYM);
+    }
+
+    #[test]
+    fn segmentize_invalid_max_length() {
+        let arr = crate::test::linestring::ls_array();
+        assert!(segmentize(&arr, 0.).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::algorithm::native::rebuild::{MapCoordSequences, SimpleCoord};
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+use geo_traits::PointTrait;
+
+/// Snap the coordinates of geometries to a regular grid.
+///
+/// Every ordinate, including Z or M, is rounded to the nearest multiple of `grid_size`. If
+/// `remove_repeated_points` is `true`, consecutive vertices of line strings, rings, and multi
+/// points that become identical after snapping are collapsed into one.
+///
+/// Note that this does not attempt to repair geometries that become invalid as a result of
+/// snapping, e.g. rings collapsing to fewer than four coordinates.
+pub trait SetPrecision {
+    type Output;
+
+    fn set_precision(&self, grid_size: f64, remove_repeated_points: bool) -> Self::Output;
+}
+
+/// Snap the coordinates of each geometry in `arr` to a grid of size `grid_size`.
+///
+/// This keeps repeated vertices; use [`SetPrecision`] to also collapse them.
+pub fn set_precision(arr: &dyn NativeArray, grid_size: f64) -> Result<Arc<dyn NativeArray>> {
+    arr.set_precision(grid_size, false)
+}
+
+fn check_grid_size(grid_size: f64) -> Result<()> {
+    if grid_size > 0.0 && grid_size.is_finite() {
+        Ok(())
+    } else {
+        Err(GeoArrowError::General(format!(
+            "grid_size must be a positive, finite number, got {grid_size}"
+        )))
+    }
+}
+
+fn snap(value: f64, grid_size: f64) -> f64 {
+    (value / grid_size).round() * grid_size
+}
+
+fn snap_coord(coord: &SimpleCoord, grid_size: f64) -> SimpleCoord {
+    SimpleCoord {
+        x: snap(coord.x, grid_size),
+        y: snap(coord.y, grid_size),
+        z: coord.z.map(|z| snap(z, grid_size)),
+        dim: coord.dim,
+    }
+}
+
+fn snap_coords(
+    coords: Vec<SimpleCoord>,
+    grid_size: f64,
+    remove_repeated_points: bool,
+) -> Vec<SimpleCoord> {
+    let mut output = coords
+        .iter()
+        .map(|coord| snap_coord(coord, grid_size))
+        .collect::<Vec<_>>();
+    if remove_repeated_points {
+        output.dedup();
+    }
+    output
+}
+
+impl SetPrecision for PointArray {
+    type Output = Result<PointArray>;
+
+    fn set_precision(&self, grid_size: f64, _remove_repeated_points: bool) -> Self::Output {
+        check_grid_size(grid_size)?;
+        let mut builder = PointBuilder::with_capacity_and_options(
+            self.dimension(),
+            self.len(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        for maybe_point in self.iter() {
+            match maybe_point.as_ref().and_then(|point| point.coord()) {
+                Some(coord) => {
+                    let coord = snap_coord(&SimpleCoord::from_coord(&coord), grid_size);
+                    builder.push_coord(Some(&coord));
+                }
+                None => builder.push_point(maybe_point.as_ref()),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+macro_rules! impl_set_precision {
+    ($type:ty) => {
+        impl SetPrecision for $type {
+            type Output = Result<$type>;
+
+            fn set_precision(&self, grid_size: f64, remove_repeated_points: bool) -> Self::Output {
+                check_grid_size(grid_size)?;
+                self.map_coord_sequences(|coords| {
+                    snap_coords(coords, grid_size, remove_repeated_points)
+                })
+            }
+        }
+    };
+}
+
+impl_set_precision!(LineStringArray);
+impl_set_precision!(PolygonArray);
+impl_set_precision!(MultiPointArray);
+impl_set_precision!(MultiLineStringArray);
+impl_set_precision!(MultiPolygonArray);
+
+impl SetPrecision for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn set_precision(&self, grid_size: f64, remove_repeated_points: bool) -> Self::Output {
+        use NativeType::*;
+
+        macro_rules! impl_downcast {
+            ($method:ident) => {
+                Arc::new(
+                    self.$method()
+                        .set_precision(grid_size, remove_repeated_points)?,
+                )
+            };
+        }
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => impl_downcast!(as_point),
+            LineString(_, _) => impl_downcast!(as_line_string),
+            Polygon(_, _) => impl_downcast!(as_polygon),
+            MultiPoint(_, _) => impl_downcast!(as_multi_point),
+            MultiLineString(_, _) => impl_downcast!(as_multi_line_string),
+            MultiPolygon(_, _) => impl_downcast!(as_multi_polygon),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use geo::line_string;
+    use geo_traits::{CoordTrait, LineStringTrait};
+
+    fn assert_coords_eq(line_string: &impl LineStringTrait<T = f64>, expected: &[(f64, f64)]) {
+        assert_eq!(line_string.num_coords(), expected.len());
+        for (coord, (x, y)) in line_string.coords().zip(expected.iter()) {
+            assert!((coord.x() - x).abs() < 1e-12);
+            assert!((coord.y() - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn snap_to_tenth() {
+        let line_string = line_string![
+            (x: 0.123, y: 1.987),
+            (x: 0.149, y: 2.01),
+            (x: 3.351, y: -0.04),
+        ];
+        let arr: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+
+        let output = set_precision(&arr, 0.1).unwrap();
+        let output = output.as_ref().as_line_string();
+        assert_coords_eq(&output.value(0), &[(0.1, 2.0), (0.1, 2.0), (3.4, -0.0)]);
+
+        let output = arr.set_precision(0.1, true).unwrap();
+        assert_coords_eq(&output.value(0), &[(0.1, 2.0), (3.4, -0.0)]);
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use geo::{coord, BooleanOps, BoundingRect, Coord, Rect};
+
+use crate::array::*;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Split geometries in longitude/latitude coordinates that cross the antimeridian.
+///
+/// An edge is considered to cross the antimeridian when the longitudes of its endpoints differ by
+/// more than 180°. Line strings are cut at ±180° into a [`MultiLineStringArray`] and polygons are
+/// cut into a [`MultiPolygonArray`], so that every part lies within `[-180, 180]`. Points and
+/// multi points are returned unchanged.
+///
+/// Polygons that enclose a pole cannot be split and return an error.
+///
+/// Note: this will currently return a two-dimensional array
+pub fn split_antimeridian(arr: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    let result: Arc<dyn NativeArray> = match arr.data_type() {
+        Point(_, _) => Arc::new(arr.as_point().clone()),
+        MultiPoint(_, _) => Arc::new(arr.as_multi_point().clone()),
+        LineString(_, _) => {
+            let output = arr
+                .as_line_string()
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|g| split_line_strings(&[g])))
+                .collect::<Vec<_>>();
+            Arc::new(MultiLineStringArray::from((output, Dimension::XY)))
+        }
+        MultiLineString(_, _) => {
+            let output = arr
+                .as_multi_line_string()
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|g| split_line_strings(&g.0)))
+                .collect::<Vec<_>>();
+            Arc::new(MultiLineStringArray::from((output, Dimension::XY)))
+        }
+        Polygon(_, _) => {
+            let output = arr
+                .as_polygon()
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|g| split_polygons(&[g])).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(MultiPolygonArray::from((output, Dimension::XY)))
+        }
+        MultiPolygon(_, _) => {
+            let output = arr
+                .as_multi_polygon()
+                .iter_geo()
+                .map(|maybe_g| maybe_g.map(|g| split_polygons(&g.0)).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(MultiPolygonArray::from((output, Dimension::XY)))
+        }
+        _ => return Err(GeoArrowError::IncorrectType("".into())),
+    };
+    Ok(result)
+}
+
+fn split_line_strings(line_strings: &[geo::LineString]) -> geo::MultiLineString {
+    geo::MultiLineString::new(line_strings.iter().flat_map(split_line_string).collect())
+}
+
+/// Cut a line string at each edge crossing the antimeridian.
+fn split_line_string(line_string: &geo::LineString) -> Vec<geo::LineString> {
+    let mut parts = vec![];
+    let mut current: Vec<Coord> = vec![];
+    for coord in line_string.coords() {
+        if let Some(prev) = current.last().copied() {
+            let dx = coord.x - prev.x;
+            if dx.abs() > 180. {
+                // Shift the next coordinate so that the edge is continuous, then find where it
+                // crosses the meridian at `edge`.
+                let (edge, shifted_x) = if dx < 0. {
+                    (180., coord.x + 360.)
+                } else {
+                    (-180., coord.x - 360.)
+                };
+                let fraction = (edge - prev.x) / (shifted_x - prev.x);
+                let y = prev.y + (coord.y - prev.y) * fraction;
+
+                let end = coord! { x: edge, y: y };
+                if prev != end {
+                    current.push(end);
+                }
+                if current.len() > 1 {
+                    parts.push(geo::LineString::new(std::mem::take(&mut current)));
+                }
+
+                let start = coord! { x: -edge, y: y };
+                if *coord != start {
+                    current.push(start);
+                }
+            }
+        }
+        current.push(*coord);
+    }
+    if current.len() > 1 || (parts.is_empty() && !current.is_empty()) {
+        parts.push(geo::LineString::new(current));
+    }
+    parts
+}
+
+fn split_polygons(polygons: &[geo::Polygon]) -> Result<geo::MultiPolygon> {
+    let mut output = vec![];
+    for polygon in polygons {
+        output.extend(split_polygon(polygon)?);
+    }
+    Ok(geo::MultiPolygon::new(output))
+}
+
+/// Shift the longitudes of a ring so that no edge spans more than 180°.
+///
+/// Returns `None` if the ring does not close after unwrapping, i.e. it encloses a pole.
+fn unwrap_ring(ring: &geo::LineString) -> Option<(geo::LineString, bool)> {
+    let mut offset = 0.;
+    let mut crosses = false;
+    let mut prev: Option<Coord> = None;
+    let mut coords = Vec::with_capacity(ring.0.len());
+    for coord in ring.coords() {
+        if let Some(prev) = prev {
+            let dx = coord.x - prev.x;
+            if dx > 180. {
+                offset -= 360.;
+                crosses = true;
+            } else if dx < -180. {
+                offset += 360.;
+                crosses = true;
+            }
+        }
+        coords.push(coord! { x: coord.x + offset, y: coord.y });
+        prev = Some(*coord);
+    }
+    (offset == 0.).then(|| (geo::LineString::new(coords), crosses))
+}
+
+/// Split a single polygon into the parts on either side of the antimeridian.
+fn split_polygon(polygon: &geo::Polygon) -> Result<Vec<geo::Polygon>> {
+    let pole_error =
+        || GeoArrowError::General("Cannot split a polygon that encloses a pole".to_string());
+
+    let (exterior, mut crosses) = unwrap_ring(polygon.exterior()).ok_or_else(pole_error)?;
+    let Some(exterior_bounds) = exterior.bounding_rect() else {
+        return Ok(vec![polygon.clone()]);
+    };
+    let center_x = exterior_bounds.center().x;
+
+    let mut interiors = Vec::with_capacity(polygon.interiors().len());
+    for interior in polygon.interiors() {
+        let (mut interior, interior_crosses) = unwrap_ring(interior).ok_or_else(pole_error)?;
+        crosses |= interior_crosses;
+        // Move each hole into the same longitude range as the exterior
+        if let Some(first) = interior.0.first() {
+            let shift = ((center_x - first.x) / 360.).round() * 360.;
+            if shift != 0. {
+                interior.0.iter_mut().for_each(|c| c.x += shift);
+            }
+        }
+        interiors.push(interior);
+    }
+
+    if !crosses {
+        return Ok(vec![polygon.clone()]);
+    }
+
+    let unwrapped = geo::Polygon::new(exterior, interiors);
+    let (min_y, max_y) = (exterior_bounds.min().y, exterior_bounds.max().y);
+    let mut parts = vec![];
+    for shift in [-360., 0., 360.] {
+        let window = Rect::new(
+            coord! { x: -180. + shift, y: min_y },
+            coord! { x: 180. + shift, y: max_y },
+        )
+        .to_polygon();
+        for mut part in unwrapped.intersection(&window) {
+            part.exterior_mut(|ring| ring.0.iter_mut().for_each(|c| c.x -= shift));
+            part.interiors_mut(|rings| {
+                rings
+                    .iter_mut()
+                    .for_each(|ring| ring.0.iter_mut().for_each(|c| c.x -= shift))
+            });
+            parts.push(part);
+        }
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ArrayBase;
+    use geo::{line_string, polygon, Area};
+
+    #[test]
+    fn split_line_string_crossing() {
+        let arr: LineStringArray = (
+            vec![line_string![(x: 170., y: 0.), (x: -170., y: 10.)]].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let result = split_antimeridian(&arr).unwrap();
+        let result = result.as_ref().as_multi_line_string();
+        assert_eq!(result.len(), 1);
+
+        let expected = geo::MultiLineString::new(vec![
+            line_string![(x: 170., y: 0.), (x: 180., y: 5.)],
+            line_string![(x: -180., y: 5.), (x: -170., y: 10.)],
+        ]);
+        assert_eq!(result.value_as_geo(0), expected);
+    }
+
+    #[test]
+    fn split_polygon_crossing() {
+        let arr: PolygonArray = (
+            vec![polygon![
+                (x: 170., y: 0.),
+                (x: -170., y: 0.),
+                (x: -170., y: 10.),
+                (x: 170., y: 10.),
+            ]]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let result = split_antimeridian(&arr).unwrap();
+        let result = result.as_ref().as_multi_polygon().value_as_geo(0);
+        assert_eq!(result.0.len(), 2);
+        for part in result.iter() {
+            assert_eq!(part.unsigned_area(), 100.);
+            let bounds = part.bounding_rect().unwrap();
+            assert!(bounds.min().x >= -180. && bounds.max().x <= 180.);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::{ArrayBase, NativeArray};
+
+/// Swap the x and y values of every coordinate, e.g. to fix data stored in latitude/longitude
+/// order.
+///
+/// Z and M values, offsets, validity, and metadata are all preserved.
+pub trait SwapXY {
+    type Output;
+
+    fn swap_xy(&self) -> Self::Output;
+}
+
+/// Swap the x and y values of every coordinate in `arr`.
+///
+/// See [`SwapXY`] for details.
+pub fn swap_xy(arr: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+    arr.swap_xy()
+}
+
+impl SwapXY for PointArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        PointArray::new(
+            self.coords.swap_xy(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for LineStringArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        LineStringArray::new(
+            self.coords.swap_xy(),
+            self.geom_offsets.clone(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for PolygonArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        PolygonArray::new(
+            self.coords.swap_xy(),
+            self.geom_offsets.clone(),
+            self.ring_offsets.clone(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for MultiPointArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        MultiPointArray::new(
+            self.coords.swap_xy(),
+            self.geom_offsets.clone(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for MultiLineStringArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        MultiLineStringArray::new(
+            self.coords.swap_xy(),
+            self.geom_offsets.clone(),
+            self.ring_offsets.clone(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for MultiPolygonArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        MultiPolygonArray::new(
+            self.coords.swap_xy(),
+            self.geom_offsets.clone(),
+            self.polygon_offsets.clone(),
+            self.ring_offsets.clone(),
+            self.validity.clone(),
+            self.metadata.clone(),
+        )
+    }
+}
+
+impl SwapXY for MixedGeometryArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        let mut output = MixedGeometryArray::new(
+            self.type_ids.clone(),
+            self.offsets.clone(),
+            self.points.swap_xy(),
+            self.line_strings.swap_xy(),
+            self.polygons.swap_xy(),
+            self.multi_points.swap_xy(),
+            self.multi_line_strings.swap_xy(),
+            self.multi_polygons.swap_xy(),
+            self.metadata.clone(),
+        );
+        // The child arrays are not sliced, so keep pointing into them at the same offset
+        output.slice_offset = self.slice_offset;
+        output
+    }
+}
+
+impl SwapXY for GeometryCollectionArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        GeometryCollectionArray::new(
+            self.array.swap_xy(),
+            self.geom_offsets.clone(),
+            self.validity.clone(),
+            self.metadata(),
+        )
+    }
+}
+
+impl SwapXY for RectArray {
+    type Output = Self;
+
+    fn swap_xy(&self) -> Self::Output {
+        RectArray::new(
+            self.lower().swap_xy(),
+            self.upper().swap_xy(),
+            self.nulls().cloned(),
+            self.metadata(),
+        )
+    }
+}
+
+impl SwapXY for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn swap_xy(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().swap_xy()),
+            LineString(_, _) => Arc::new(self.as_line_string().swap_xy()),
+            Polygon(_, _) => Arc::new(self.as_polygon().swap_xy()),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().swap_xy()),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().swap_xy()),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().swap_xy()),
+            Mixed(_, _) => Arc::new(self.as_mixed().swap_xy()),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().swap_xy()),
+            Rect(_) => Arc::new(self.as_rect().swap_xy()),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn swap_point() {
+        let arr: PointArray = (vec![geo::point!(x: 10., y: 20.)].as_slice(), Dimension::XY).into();
+        let result = swap_xy(&arr).unwrap();
+        assert_eq!(
+            result.as_ref().as_point().value_as_geo(0),
+            geo::point!(x: 20., y: 10.)
+        );
+    }
+
+    #[test]
+    fn swap_interleaved_keeps_z() {
+        let coords =
+            InterleavedCoordBuffer::new(vec![1., 2., 3., 4., 5., 6.].into(), Dimension::XYZ);
+        let swapped = coords.swap_xy();
+        assert_eq!(swapped.coords().to_vec(), vec![2., 1., 3., 5., 4., 6.]);
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, FixedSizeListArray, ListArray};
+use arrow_schema::{DataType, Field};
+
+use crate::array::{CoordBuffer, CoordType, LineStringArray};
+use crate::ArrayBase;
+
+/// Expose the coordinates of each line string as a plain Arrow `List<FixedSizeList<Float64>>`.
+///
+/// Unlike the GeoArrow representation of `arr`, the output carries no extension metadata and uses
+/// the default `item` field names, so that it can be consumed by any Arrow-based tool, e.g. to
+/// build ragged tensors. Each coordinate is a fixed-size list of 2 or 3 values, depending on the
+/// dimension of `arr`. The list offsets and validity are those of `arr`.
+pub fn to_coord_list(arr: &LineStringArray) -> ListArray {
+    let coords = match arr.coords().clone().into_coord_type(CoordType::Interleaved) {
+        CoordBuffer::Interleaved(coords) => coords,
+        CoordBuffer::Separated(_) => unreachable!(),
+    };
+    let size = coords.dim().size() as i32;
+    let coord_list = FixedSizeListArray::new(
+        Arc::new(Field::new("item", DataType::Float64, false)),
+        size,
+        Arc::new(coords.values_array()),
+        None,
+    );
+    ListArray::new(
+        Arc::new(Field::new("item", coord_list.data_type().clone(), false)),
+        arr.geom_offsets().clone(),
+        Arc::new(coord_list),
+        arr.nulls().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::linestring::ls_array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use geo_traits::CoordTrait;
+
+    #[test]
+    fn offsets_match() {
+        let arr = ls_array().into_coord_type(CoordType::Separated);
+        let list = to_coord_list(&arr);
+        assert_eq!(list.len(), arr.len());
+        assert_eq!(list.offsets(), arr.geom_offsets());
+
+        let first = list.value(0);
+        let first = first.as_fixed_size_list();
+        assert_eq!(first.value_length(), 2);
+        let coord = first.value(0);
+        let coord = coord.as_primitive::<Float64Type>();
+        let expected = arr.coords().value(0);
+        assert_eq!(coord.values().to_vec(), vec![expected.x(), expected.y()]);
+    }
+}
@@ -4,6 +4,8 @@ use crate::chunked_array::*;
 use crate::datatypes::NativeType;
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// Computes the total bounds (extent) of the input.
 pub trait TotalBounds {
@@ -82,12 +84,25 @@ impl TotalBounds for &dyn NativeArray {
     }
 }
 
+/// If the `rayon` feature is enabled, the bounds of each chunk are computed in parallel and then
+/// reduced into a single [`BoundingRect`].
 impl<G: NativeArray> TotalBounds for ChunkedGeometryArray<G> {
     fn total_bounds(&self) -> BoundingRect {
-        let bounding_rects = self.map(|chunk| chunk.as_ref().total_bounds());
-        bounding_rects
-            .into_iter()
-            .fold(BoundingRect::default(), |acc, x| acc + x)
+        #[cfg(feature = "rayon")]
+        {
+            self.chunks
+                .par_iter()
+                .map(|chunk| chunk.as_ref().total_bounds())
+                .reduce(BoundingRect::default, |acc, x| acc + x)
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.chunks
+                .iter()
+                .map(|chunk| chunk.as_ref().total_bounds())
+                .fold(BoundingRect::default(), |acc, x| acc + x)
+        }
     }
 }
 
@@ -126,6 +141,25 @@ mod test {
         dbg!(total_bounds);
     }
 
+    #[test]
+    fn test_chunked_matches_serial() {
+        let chunks = vec![
+            polygon::p_array(),
+            polygon::p_array().slice(1, 1),
+            polygon::p_array().slice(0, 1),
+        ];
+        let serial = chunks
+            .iter()
+            .map(|chunk| chunk.total_bounds())
+            .fold(BoundingRect::default(), |acc, x| acc + x);
+        let chunked = ChunkedGeometryArray::new(chunks).total_bounds();
+
+        assert_eq!(chunked.minx(), serial.minx());
+        assert_eq!(chunked.miny(), serial.miny());
+        assert_eq!(chunked.maxx(), serial.maxx());
+        assert_eq!(chunked.maxy(), serial.maxy());
+    }
+
     // #[test]
     // fn test_dyn_chunked_array_dyn_array() {
     //     let dyn_arrs: Vec<Arc<dyn NativeArray>> =
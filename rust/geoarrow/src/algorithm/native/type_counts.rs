@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use geo_traits::{GeometryCollectionTrait, GeometryTrait};
+
+use crate::array::*;
+use crate::datatypes::{Dimension, GeometryType, GeometryTypeAndDimension, NativeType};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Count the number of geometries of each type in `arr`.
+///
+/// Homogeneous arrays produce a single entry keyed by the array's own geometry type, while
+/// [MixedGeometryArray]s are tallied per element. For [GeometryCollectionArray]s, the members of
+/// each collection are tallied, so a row holding a point and a line string adds one to each of
+/// those types. Null elements are not included in the counts; use
+/// [`null_count`][crate::ArrayBase::null_count] to count them.
+pub fn type_counts(arr: &dyn NativeArray) -> HashMap<GeometryTypeAndDimension, usize> {
+    use NativeType::*;
+
+    let dim = arr.dimension();
+    let constant = |geometry_type| {
+        let mut counts = HashMap::with_capacity(1);
+        let valid_count = arr.len() - arr.null_count();
+        if valid_count > 0 {
+            counts.insert(
+                GeometryTypeAndDimension::new(geometry_type, dim),
+                valid_count,
+            );
+        }
+        counts
+    };
+
+    match arr.data_type() {
+        Point(_, _) => constant(GeometryType::Point),
+        LineString(_, _) => constant(GeometryType::LineString),
+        Polygon(_, _) => constant(GeometryType::Polygon),
+        MultiPoint(_, _) => constant(GeometryType::MultiPoint),
+        MultiLineString(_, _) => constant(GeometryType::MultiLineString),
+        MultiPolygon(_, _) => constant(GeometryType::MultiPolygon),
+        Rect(_) => constant(GeometryType::Rect),
+        Mixed(_, _) => {
+            let mut counts = HashMap::new();
+            for geom in arr.as_mixed().iter().flatten() {
+                add_geometry(&mut counts, &geom, dim);
+            }
+            counts
+        }
+        GeometryCollection(_, _) => {
+            let mut counts = HashMap::new();
+            for collection in arr.as_geometry_collection().iter().flatten() {
+                for geom in collection.geometries() {
+                    add_geometry(&mut counts, &geom, dim);
+                }
+            }
+            counts
+        }
+    }
+}
+
+fn add_geometry(
+    counts: &mut HashMap<GeometryTypeAndDimension, usize>,
+    geom: &impl GeometryTrait,
+    dim: Dimension,
+) {
+    let key = GeometryTypeAndDimension::new(geometry_type(geom), dim);
+    *counts.entry(key).or_insert(0) += 1;
+}
+
+fn geometry_type(geom: &impl GeometryTrait) -> GeometryType {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(_) => GeometryType::Point,
+        LineString(_) | Line(_) => GeometryType::LineString,
+        Polygon(_) | Triangle(_) => GeometryType::Polygon,
+        MultiPoint(_) => GeometryType::MultiPoint,
+        MultiLineString(_) => GeometryType::MultiLineString,
+        MultiPolygon(_) => GeometryType::MultiPolygon,
+        GeometryCollection(_) => GeometryType::GeometryCollection,
+        Rect(_) => GeometryType::Rect,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point};
+
+    #[test]
+    fn type_counts_mixed() {
+        let arr: MixedGeometryArray = (
+            vec![
+                Some(geo::Geometry::Point(point::p0())),
+                Some(geo::Geometry::LineString(linestring::ls0())),
+                Some(geo::Geometry::Point(point::p1())),
+            ],
+            Dimension::XY,
+        )
+            .try_into()
+            .unwrap();
+
+        let counts = type_counts(&arr);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(
+            counts[&GeometryTypeAndDimension::new(GeometryType::Point, Dimension::XY)],
+            2
+        );
+        assert_eq!(
+            counts[&GeometryTypeAndDimension::new(GeometryType::LineString, Dimension::XY)],
+            1
+        );
+    }
+
+    #[test]
+    fn type_counts_homogeneous() {
+        let arr = point::point_array();
+        let counts = type_counts(&arr);
+        assert_eq!(counts.len(), 1);
+        assert_eq!(
+            counts[&GeometryTypeAndDimension::new(GeometryType::Point, Dimension::XY)],
+            arr.len()
+        );
+    }
+
+    #[test]
+    fn type_counts_geometry_collection() {
+        let collections = vec![
+            geo::GeometryCollection::new_from(vec![
+                geo::Geometry::Point(point::p0()),
+                geo::Geometry::LineString(linestring::ls0()),
+            ]),
+            geo::GeometryCollection::new_from(vec![geo::Geometry::Point(point::p1())]),
+        ];
+        let arr: GeometryCollectionArray = GeometryCollectionBuilder::from_geometry_collections(
+            &collections,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        let counts = type_counts(&arr);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(
+            counts[&GeometryTypeAndDimension::new(GeometryType::Point, Dimension::XY)],
+            2
+        );
+        assert_eq!(
+            counts[&GeometryTypeAndDimension::new(GeometryType::LineString, Dimension::XY)],
+            1
+        );
+    }
+}
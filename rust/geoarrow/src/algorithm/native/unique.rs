@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::UInt32Array;
+
+use crate::algorithm::native::Take;
+use crate::error::Result;
+use crate::io::wkb::to_wkb;
+use crate::NativeArray;
+
+/// Remove duplicate geometries from an array, keeping the first occurrence of each.
+///
+/// Geometries are compared by their WKB encoding, so only exact duplicates are removed. All null
+/// values collapse into a single null, kept at the position of the first null.
+///
+/// Returns the distinct geometries along with the index of the input row each was taken from.
+pub fn unique(arr: &dyn NativeArray) -> Result<(Arc<dyn NativeArray>, UInt32Array)> {
+    let wkb_arr = to_wkb::<i64>(arr).into_inner();
+
+    let mut seen: HashSet<&[u8]> = HashSet::with_capacity(wkb_arr.len());
+    let mut seen_null = false;
+    let mut indices = vec![];
+    for (i, maybe_wkb) in wkb_arr.iter().enumerate() {
+        let is_first = match maybe_wkb {
+            Some(wkb) => seen.insert(wkb),
+            None => !std::mem::replace(&mut seen_null, true),
+        };
+        if is_first {
+            indices.push(i as u32);
+        }
+    }
+
+    let indices = UInt32Array::from(indices);
+    let output = arr.take(&indices)?;
+    Ok((output, indices))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::datatypes::Dimension;
+    use crate::test::point::{p0, p1};
+    use crate::ArrayBase;
+
+    #[test]
+    fn unique_keeps_first_occurrence() {
+        let arr: PointArray = (vec![p0(), p1(), p0()].as_slice(), Dimension::XY).into();
+
+        let (output, indices) = unique(&arr).unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(indices, UInt32Array::from(vec![0, 1]));
+    }
+
+    #[test]
+    fn unique_collapses_nulls() {
+        let arr: PointArray = (vec![Some(p0()), None, Some(p0()), None], Dimension::XY).into();
+
+        let (output, indices) = unique(&arr).unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(indices, UInt32Array::from(vec![0, 1]));
+        assert!(output.is_null(1));
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::NativeArray;
+
+/// Iterate over `arr` in consecutive, non-overlapping windows of `size` rows.
+///
+/// Each window is a zero-copy [`slice`][NativeArray::slice] of `arr`. If the length of `arr` is
+/// not a multiple of `size`, the last window holds the remaining rows.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+pub fn windows(
+    arr: &dyn NativeArray,
+    size: usize,
+) -> impl Iterator<Item = Arc<dyn NativeArray>> + '_ {
+    assert!(size > 0, "window size must be at least 1");
+    (0..arr.len())
+        .step_by(size)
+        .map(move |offset| arr.slice(offset, size.min(arr.len() - offset)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsNativeArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use geo::point;
+
+    #[test]
+    fn uneven_windows() {
+        let points = (0..5)
+            .map(|i| point!(x: i as f64, y: 0.))
+            .collect::<Vec<_>>();
+        let arr: PointArray = (points.as_slice(), Dimension::XY).into();
+
+        let windows = windows(&arr, 2).collect::<Vec<_>>();
+        let lengths = windows
+            .iter()
+            .map(|window| window.len())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2, 1]);
+        assert_eq!(
+            windows[2].as_ref().as_point().value_as_geo(0),
+            point!(x: 4., y: 0.)
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! Bindings to the [`proj`] crate for coordinate reprojection.
 
+use std::sync::Arc;
+
+use crate::array::metadata::{ArrayMetadata, Crs};
 use crate::array::*;
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 use proj::{Proj, Transform};
 
 /// Reproject an array using PROJ
@@ -64,6 +68,104 @@ iter_geo_impl!(
 );
 iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder, push_multi_polygon);
 
+/// Reproject an array between two known CRSs, such as `"EPSG:4326"` and `"EPSG:3857"`.
+///
+/// The CRS of the output's [`ArrayMetadata`] is set to `to`. To reproject many arrays between the
+/// same CRSs, use a [`Reprojector`] instead.
+pub fn reproject(arr: &dyn NativeArray, from: &str, to: &str) -> Result<Arc<dyn NativeArray>> {
+    Reprojector::new(from, to)?.transform(arr)
+}
+
+/// A reusable transformation between two coordinate reference systems.
+///
+/// Creating a [`Proj`] pipeline is expensive, so when many arrays are reprojected between the same
+/// pair of CRSs, build a [`Reprojector`] once and call [`transform`][Self::transform] on each
+/// array.
+pub struct Reprojector {
+    proj: Proj,
+    target_crs: Option<String>,
+}
+
+impl Reprojector {
+    /// Build a reprojector between two known CRSs, such as `"EPSG:4326"` and `"EPSG:3857"`.
+    pub fn new(from: &str, to: &str) -> Result<Self> {
+        let proj = Proj::new_known_crs(from, to, None)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        Ok(Self {
+            proj,
+            target_crs: Some(to.to_string()),
+        })
+    }
+
+    /// Wrap an existing [`Proj`] pipeline.
+    ///
+    /// The target CRS of an arbitrary pipeline is not known, so arrays reprojected with this
+    /// reprojector have no CRS in their metadata.
+    pub fn from_proj(proj: Proj) -> Self {
+        Self {
+            proj,
+            target_crs: None,
+        }
+    }
+
+    /// The underlying [`Proj`] pipeline.
+    pub fn proj(&self) -> &Proj {
+        &self.proj
+    }
+
+    /// Reproject an array with this pipeline.
+    ///
+    /// The output keeps the `edges` of the input's metadata, but its CRS is replaced by the target
+    /// CRS of this reprojector.
+    ///
+    /// Note: this will currently return a two-dimensional array
+    pub fn transform(&self, arr: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match arr.data_type() {
+            Point(_, _) => Arc::new(arr.as_point().reproject(&self.proj)?),
+            LineString(_, _) => Arc::new(arr.as_line_string().reproject(&self.proj)?),
+            Polygon(_, _) => Arc::new(arr.as_polygon().reproject(&self.proj)?),
+            MultiPoint(_, _) => Arc::new(arr.as_multi_point().reproject(&self.proj)?),
+            MultiLineString(_, _) => Arc::new(arr.as_multi_line_string().reproject(&self.proj)?),
+            MultiPolygon(_, _) => Arc::new(arr.as_multi_polygon().reproject(&self.proj)?),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result.with_metadata(self.output_metadata(&arr.metadata())))
+    }
+
+    /// The metadata of an array reprojected from an array with `source` metadata.
+    fn output_metadata(&self, source: &ArrayMetadata) -> Arc<ArrayMetadata> {
+        let metadata = ArrayMetadata {
+            edges: source.edges.clone(),
+            ..Default::default()
+        };
+        let metadata = match &self.target_crs {
+            Some(crs) if is_authority_code(crs) => {
+                metadata.with_crs(Crs::AuthorityCode(crs.clone()))
+            }
+            Some(crs) => metadata.with_crs(Crs::Unknown(serde_json::Value::String(crs.clone()))),
+            None => metadata,
+        };
+        Arc::new(metadata)
+    }
+}
+
+/// Whether `crs` is an identifier in the form `AUTHORITY:CODE`.
+fn is_authority_code(crs: &str) -> bool {
+    matches!(crs.split_once(':'), Some((authority, code))
+        if !authority.is_empty()
+            && !code.is_empty()
+            && authority.chars().all(|c| c.is_ascii_alphanumeric())
+            && code.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+impl std::fmt::Debug for Reprojector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reprojector").finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::trait_::ArrayAccessor;
@@ -87,4 +189,48 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    #[test]
+    fn reprojector_matches_per_call() {
+        let reprojector = Reprojector::new("EPSG:4326", "EPSG:3857").unwrap();
+
+        let point_array: PointArray =
+            (vec![Some(p0()), Some(p1()), Some(p2())], Dimension::XY).into();
+        let polygon_array = crate::test::polygon::p_array();
+
+        let out_points = reprojector.transform(&point_array).unwrap();
+        let out_polygons = reprojector.transform(&polygon_array).unwrap();
+
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        assert_eq!(
+            out_points.as_ref().as_point(),
+            &point_array.reproject(&proj).unwrap()
+        );
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        assert_eq!(
+            out_polygons.as_ref().as_polygon(),
+            &polygon_array.reproject(&proj).unwrap()
+        );
+    }
+
+    #[test]
+    fn reproject_sets_target_crs() {
+        let metadata = Arc::new(ArrayMetadata {
+            crs: Some(serde_json::Value::String("EPSG:4326".to_string())),
+            crs_type: Some("authority_code".to_string()),
+            edges: None,
+        });
+        let point_array: PointArray = (vec![Some(p0()), Some(p1())], Dimension::XY).into();
+        let point_array = point_array.with_metadata(metadata);
+
+        let output = reproject(point_array.as_ref(), "EPSG:4326", "EPSG:3857").unwrap();
+        assert_eq!(
+            output.metadata().crs,
+            Some(serde_json::Value::String("EPSG:3857".to_string()))
+        );
+        assert_eq!(
+            output.metadata().crs_type.as_deref(),
+            Some("authority_code")
+        );
+    }
 }
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
-use crate::array::binary::WKBCapacity;
+use crate::array::binary::{WKBCapacity, WkbSummary};
 use crate::array::metadata::ArrayMetadata;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
 use crate::array::{CoordType, WKBBuilder};
 use crate::datatypes::{NativeType, SerializedType};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::WKB;
+use geo_traits::to_geo::ToGeoGeometry;
 use geo_traits::GeometryTrait;
 // use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::trait_::{ArrayAccessor, ArrayBase, IntoArrow, SerializedArray};
@@ -74,6 +75,36 @@ impl<O: OffsetSizeTrait> WKBArray<O> {
         validity_len + self.buffer_lengths().num_bytes::<O>()
     }
 
+    /// Iterate over the geometries of this array, parsing each one separately.
+    ///
+    /// Unlike [`iter_geo`][ArrayAccessor::iter_geo], which panics on invalid WKB, this yields an
+    /// error for each row that fails to parse, so that valid rows can still be processed. Null
+    /// rows yield `Ok(None)`.
+    pub fn try_iter_geo(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<Option<geo::Geometry>>> + '_ {
+        self.iter().map(|maybe_wkb| {
+            maybe_wkb
+                .map(|wkb| Ok(wkb.parse()?.to_geometry()))
+                .transpose()
+        })
+    }
+
+    /// Count the geometries, coordinates, and parts in this array.
+    ///
+    /// This reads the structure of each WKB geometry once without building a native array, so it
+    /// is a cheap way to inspect an array before deciding whether to parse it.
+    pub fn summary(&self) -> Result<WkbSummary> {
+        let mut summary = WkbSummary::default();
+        for maybe_wkb in self.iter() {
+            match maybe_wkb {
+                Some(wkb) => summary.add_geometry(Some(&wkb.parse()?)),
+                None => summary.add_geometry(None::<&geo::Geometry>),
+            }
+        }
+        Ok(summary)
+    }
+
     pub fn into_inner(self) -> GenericBinaryArray<O> {
         self.array
     }
@@ -211,6 +242,11 @@ impl<O: OffsetSizeTrait> From<GenericBinaryArray<O>> for WKBArray<O> {
     }
 }
 
+/// Import a `Binary` or `LargeBinary` array as a [`WKBArray<i32>`].
+///
+/// A `Binary` input is zero-copy: the offsets, values, and validity buffers are shared with the
+/// input and offsets are never upsized to i64. A `LargeBinary` input has its offsets downsized to
+/// i32, which fails if the values buffer is too large to be indexed with i32 offsets.
 impl TryFrom<&dyn Array> for WKBArray<i32> {
     type Error = GeoArrowError;
     fn try_from(value: &dyn Array) -> Result<Self> {
@@ -232,6 +268,11 @@ impl TryFrom<&dyn Array> for WKBArray<i32> {
     }
 }
 
+/// Import a `Binary` or `LargeBinary` array as a [`WKBArray<i64>`].
+///
+/// A `LargeBinary` input is zero-copy. A `Binary` input has its offsets upsized to i64, which
+/// allocates a new offsets buffer twice the size of the original. Prefer [`WKBArray<i32>`] for
+/// `Binary` input unless i64 offsets are required downstream.
 impl TryFrom<&dyn Array> for WKBArray<i64> {
     type Error = GeoArrowError;
     fn try_from(value: &dyn Array) -> Result<Self> {
@@ -357,4 +398,72 @@ mod test {
         // We just need to ensure that the iterator runs
         wkb_arr.iter_geo().for_each(|_x| ());
     }
+
+    #[test]
+    fn binary_to_i32_is_zero_copy() {
+        let binary_arr = BinaryArray::from_opt_vec(vec![Some(b"ab".as_ref()), None, Some(b"cde")]);
+        let wkb_arr = WKBArray::<i32>::try_from(&binary_arr as &dyn Array).unwrap();
+
+        let offsets = wkb_arr.array.offsets().inner().inner();
+        assert_eq!(
+            offsets.as_ptr(),
+            binary_arr.offsets().inner().inner().as_ptr()
+        );
+        assert_eq!(offsets.len(), 4 * std::mem::size_of::<i32>());
+        assert_eq!(
+            wkb_arr.array.values().as_ptr(),
+            binary_arr.values().as_ptr()
+        );
+
+        // Upsizing to i64 allocates new offsets
+        let large_wkb_arr = WKBArray::<i64>::try_from(&binary_arr as &dyn Array).unwrap();
+        let large_offsets = large_wkb_arr.array.offsets().inner().inner();
+        assert_ne!(large_offsets.as_ptr(), offsets.as_ptr());
+        assert_eq!(large_offsets.len(), 4 * std::mem::size_of::<i64>());
+    }
+
+    #[test]
+    fn summary_mixed() {
+        let geoms: Vec<Option<geo::Geometry>> = vec![
+            Some(geo::point!(x: 0., y: 1.).into()),
+            None,
+            Some(geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)].into()),
+            Some(
+                geo::MultiPolygon::new(vec![
+                    geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+                    geo::polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+                ])
+                .into(),
+            ),
+        ];
+        let wkb_arr = WKBArray::<i32>::try_from(geoms).unwrap();
+
+        let summary = wkb_arr.summary().unwrap();
+        assert_eq!(summary.num_geometries(), 3);
+        assert_eq!(summary.num_nulls(), 1);
+        // 1 point coord, 3 line string coords, and two closed rings of 4 coords each
+        assert_eq!(summary.num_coords(), 12);
+        assert_eq!(summary.max_parts(), 2);
+    }
+
+    #[test]
+    fn try_iter_geo() {
+        let point = crate::io::wkb::geometry_to_wkb(&geo::point!(x: 1., y: 2.));
+        let malformed = [1, 1, 0];
+        let binary_arr =
+            BinaryArray::from_opt_vec(vec![Some(point.as_slice()), Some(&malformed[..]), None]);
+        let wkb_arr = WKBArray::from(binary_arr);
+
+        let results = wkb_arr.try_iter_geo().collect::<Vec<_>>();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &Some(geo::Geometry::Point(geo::point!(x: 1., y: 2.)))
+        );
+        assert!(matches!(
+            results[1],
+            Err(GeoArrowError::ParseError { row: 1, .. })
+        ));
+        assert!(matches!(results[2], Ok(None)));
+    }
 }
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 
-use crate::array::binary::WKBCapacity;
+use crate::array::binary::dialect::iso_to_ewkb;
+use crate::array::binary::{WKBCapacity, WkbDialect};
 use crate::array::metadata::ArrayMetadata;
 use crate::error::{GeoArrowError, Result};
 use arrow_array::builder::GenericBinaryBuilder;
 use arrow_array::OffsetSizeTrait;
+use geo_traits::to_geo::{ToGeoLine, ToGeoRect, ToGeoTriangle};
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
     MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
@@ -17,11 +19,56 @@ use wkb::Endianness;
 
 use super::array::WKBArray;
 
+/// The byte order used when encoding geometries as WKB.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Big endian, also known as XDR.
+    BigEndian,
+    /// Little endian, also known as NDR.
+    #[default]
+    LittleEndian,
+}
+
+impl From<ByteOrder> for Endianness {
+    fn from(value: ByteOrder) -> Self {
+        match value {
+            ByteOrder::BigEndian => Endianness::BigEndian,
+            ByteOrder::LittleEndian => Endianness::LittleEndian,
+        }
+    }
+}
+
 /// The GeoArrow equivalent to `Vec<Option<WKB>>`: a mutable collection of WKB buffers.
 ///
+/// Geometries are encoded as little endian ISO WKB unless another byte order or dialect is set
+/// with [`with_byte_order`][Self::with_byte_order] or [`with_dialect`][Self::with_dialect].
+///
 /// Converting a [`WKBBuilder`] into a [`WKBArray`] is `O(1)`.
 #[derive(Debug)]
-pub struct WKBBuilder<O: OffsetSizeTrait>(GenericBinaryBuilder<O>, Arc<ArrayMetadata>);
+pub struct WKBBuilder<O: OffsetSizeTrait> {
+    builder: GenericBinaryBuilder<O>,
+    metadata: Arc<ArrayMetadata>,
+    byte_order: ByteOrder,
+    dialect: WkbDialect,
+}
+
+/// Write a geometry with one of the `wkb` writer functions, respecting the builder's byte order
+/// and dialect.
+macro_rules! write_geometry {
+    ($self:ident, $write_fn:ident, $geom:expr) => {
+        match $self.dialect {
+            WkbDialect::Iso => {
+                $write_fn(&mut $self.builder, $geom, $self.byte_order.into()).unwrap();
+                $self.builder.append_value("")
+            }
+            WkbDialect::Ewkb { srid } => {
+                let mut buf = Vec::new();
+                $write_fn(&mut buf, $geom, $self.byte_order.into()).unwrap();
+                $self.builder.append_value(iso_to_ewkb(&buf, srid))
+            }
+        }
+    };
+}
 
 impl<O: OffsetSizeTrait> Default for WKBBuilder<O> {
     fn default() -> Self {
@@ -47,13 +94,37 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     }
 
     pub fn with_capacity_and_options(capacity: WKBCapacity, metadata: Arc<ArrayMetadata>) -> Self {
-        Self(
-            GenericBinaryBuilder::with_capacity(
+        Self {
+            builder: GenericBinaryBuilder::with_capacity(
                 capacity.offsets_capacity,
                 capacity.buffer_capacity,
             ),
             metadata,
-        )
+            byte_order: ByteOrder::default(),
+            dialect: WkbDialect::default(),
+        }
+    }
+
+    /// Set the byte order used to encode geometries pushed to this builder.
+    pub fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// The byte order used to encode geometries pushed to this builder.
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Set the WKB dialect used to encode geometries pushed to this builder.
+    pub fn with_dialect(mut self, dialect: WkbDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// The WKB dialect used to encode geometries pushed to this builder.
+    pub fn dialect(&self) -> WkbDialect {
+        self.dialect
     }
 
     pub fn with_capacity_from_iter<'a>(
@@ -79,10 +150,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_point(&mut self, geom: Option<&impl PointTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_point(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_point, geom)
         } else {
-            self.0.append_null();
+            self.builder.append_null();
         }
     }
 
@@ -90,10 +160,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_line_string(&mut self, geom: Option<&impl LineStringTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_line_string(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_line_string, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -101,10 +170,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_polygon(&mut self, geom: Option<&impl PolygonTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_polygon(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_polygon, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -112,10 +180,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_multi_point(&mut self, geom: Option<&impl MultiPointTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_multi_point(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_multi_point, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -123,10 +190,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_multi_line_string(&mut self, geom: Option<&impl MultiLineStringTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_multi_line_string(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_multi_line_string, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -134,14 +200,15 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
     #[inline]
     pub fn push_multi_polygon(&mut self, geom: Option<&impl MultiPolygonTrait<T = f64>>) {
         if let Some(geom) = geom {
-            write_multi_polygon(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_multi_polygon, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
     /// Push a Geometry onto the end of this builder
+    ///
+    /// Rects and triangles are written as 2D polygons, and lines as 2D line strings.
     #[inline]
     pub fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) {
         use GeometryType::*;
@@ -160,10 +227,12 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
                 GeometryCollection(geometry_collection) => {
                     self.push_geometry_collection(Some(geometry_collection))
                 }
-                Rect(_) | Line(_) | Triangle(_) => todo!(),
+                Rect(rect) => self.push_polygon(Some(&rect.to_rect().to_polygon())),
+                Triangle(triangle) => self.push_polygon(Some(&triangle.to_triangle().to_polygon())),
+                Line(line) => self.push_line_string(Some(&geo::LineString::from(line.to_line()))),
             }
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -174,10 +243,9 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         geom: Option<&impl GeometryCollectionTrait<T = f64>>,
     ) {
         if let Some(geom) = geom {
-            write_geometry_collection(&mut self.0, geom, Endianness::LittleEndian).unwrap();
-            self.0.append_value("")
+            write_geometry!(self, write_geometry_collection, geom)
         } else {
-            self.0.append_null()
+            self.builder.append_null()
         }
     }
 
@@ -228,6 +296,95 @@ impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<Vec<Option<G>>> for
 
 impl<O: OffsetSizeTrait> From<WKBBuilder<O>> for WKBArray<O> {
     fn from(mut other: WKBBuilder<O>) -> Self {
-        Self::new(other.0.finish(), other.1)
+        Self::new(other.builder.finish(), other.metadata)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn big_endian() {
+        let mut builder = WKBBuilder::<i32>::new().with_byte_order(ByteOrder::BigEndian);
+        builder.push_point(Some(&point::p0()));
+        builder.push_point(None);
+        let arr = builder.finish();
+
+        let wkb = arr.value(0);
+        let buf: &[u8] = wkb.as_ref();
+        assert_eq!(buf[0], 0x00);
+        // The geometry type is also big endian
+        assert_eq!(&buf[1..5], &[0, 0, 0, 1]);
+        assert_eq!(arr.value_as_geo(0), geo::Geometry::Point(point::p0()));
+    }
+
+    #[test]
+    fn little_endian_default() {
+        let mut builder = WKBBuilder::<i32>::new();
+        builder.push_point(Some(&point::p0()));
+        let arr = builder.finish();
+        let wkb = arr.value(0);
+        let buf: &[u8] = wkb.as_ref();
+        assert_eq!(buf[0], 0x01);
+    }
+
+    fn type_word(buf: &[u8]) -> u32 {
+        u32::from_le_bytes(buf[1..5].try_into().unwrap())
+    }
+
+    #[test]
+    fn iso_and_ewkb() {
+        let point_z_array = point::point_z_array();
+        let geom = point_z_array.value(0);
+
+        let mut iso_builder = WKBBuilder::<i32>::new();
+        iso_builder.push_point(Some(&geom));
+        let iso_arr = iso_builder.finish();
+        let iso = iso_arr.value(0);
+        assert_eq!(type_word(iso.as_ref()), 1001);
+
+        let mut ewkb_builder =
+            WKBBuilder::<i32>::new().with_dialect(WkbDialect::Ewkb { srid: Some(4326) });
+        ewkb_builder.push_point(Some(&geom));
+        let ewkb_arr = ewkb_builder.finish();
+        let ewkb = ewkb_arr.value(0);
+        let buf: &[u8] = ewkb.as_ref();
+        assert_eq!(type_word(buf), 0xA000_0001);
+        assert_eq!(i32::from_le_bytes(buf[5..9].try_into().unwrap()), 4326);
+        // The coordinates are identical to the ISO encoding
+        assert_eq!(&buf[9..], &iso.as_ref()[5..]);
+    }
+
+    #[test]
+    fn ewkb_nested_without_srid() {
+        let multi_point = geo::MultiPoint::new(vec![point::p0(), point::p1()]);
+        let mut builder =
+            WKBBuilder::<i32>::new().with_dialect(WkbDialect::Ewkb { srid: Some(4326) });
+        builder.push_multi_point(Some(&multi_point));
+        let arr = builder.finish();
+        let wkb = arr.value(0);
+        let buf: &[u8] = wkb.as_ref();
+
+        assert_eq!(type_word(buf), 0x2000_0004);
+        // Header (5 bytes), SRID (4 bytes), number of points (4 bytes)
+        let nested = &buf[13..];
+        assert_eq!(type_word(nested), 1);
+        assert_eq!(nested.len(), 2 * (5 + 16));
+    }
+
+    #[test]
+    fn ewkb_big_endian() {
+        let mut builder = WKBBuilder::<i32>::new()
+            .with_byte_order(ByteOrder::BigEndian)
+            .with_dialect(WkbDialect::Ewkb { srid: None });
+        builder.push_point(Some(&point::p0()));
+        let arr = builder.finish();
+        let wkb = arr.value(0);
+        let buf: &[u8] = wkb.as_ref();
+        assert_eq!(&buf[..5], &[0, 0, 0, 0, 1]);
+        assert_eq!(buf.len(), 5 + 16);
     }
 }
@@ -2,6 +2,7 @@ use std::ops::Add;
 
 use arrow_array::OffsetSizeTrait;
 
+use geo_traits::to_geo::{ToGeoLine, ToGeoRect, ToGeoTriangle};
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
     MultiPolygonTrait, PointTrait, PolygonTrait,
@@ -127,7 +128,9 @@ impl WKBCapacity {
                 MultiLineString(p) => self.add_multi_line_string(Some(p)),
                 MultiPolygon(p) => self.add_multi_polygon(Some(p)),
                 GeometryCollection(p) => self.add_geometry_collection(Some(p)),
-                Rect(_) | Line(_) | Triangle(_) => todo!(),
+                Rect(g) => self.add_polygon(Some(&g.to_rect().to_polygon())),
+                Triangle(g) => self.add_polygon(Some(&g.to_triangle().to_polygon())),
+                Line(g) => self.add_line_string(Some(&geo::LineString::from(g.to_line()))),
             }
         } else {
             self.offsets_capacity += 1;
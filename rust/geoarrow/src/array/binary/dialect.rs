@@ -0,0 +1,118 @@
+//! Support for writing extended WKB (EWKB), the variant of WKB used by PostGIS.
+
+/// The flavor of WKB produced by a [`WKBBuilder`][super::WKBBuilder].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WkbDialect {
+    /// ISO WKB, where dimensions are encoded by adding 1000, 2000, or 3000 to the geometry type.
+    #[default]
+    Iso,
+    /// Extended WKB, as used by PostGIS.
+    ///
+    /// Dimensions are encoded as high bit flags on the geometry type. If `srid` is provided, it is
+    /// written after the type of each top-level geometry, and the SRID flag is set.
+    Ewkb { srid: Option<i32> },
+}
+
+pub(crate) const EWKB_Z_FLAG: u32 = 0x8000_0000;
+pub(crate) const EWKB_M_FLAG: u32 = 0x4000_0000;
+pub(crate) const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Convert a single ISO WKB geometry to EWKB, keeping its byte order.
+///
+/// The SRID, if any, is only written on the top-level geometry. This assumes that `buf` is valid
+/// ISO WKB, as produced by the `wkb` writer.
+pub(crate) fn iso_to_ewkb(buf: &[u8], srid: Option<i32>) -> Vec<u8> {
+    let extra_len = if srid.is_some() { 4 } else { 0 };
+    let mut out = Vec::with_capacity(buf.len() + extra_len);
+    let mut offset = 0;
+    convert_geometry(buf, &mut offset, &mut out, srid);
+    out
+}
+
+fn convert_geometry(buf: &[u8], offset: &mut usize, out: &mut Vec<u8>, srid: Option<i32>) {
+    let byte_order = buf[*offset];
+    let little_endian = byte_order == 1;
+    copy_bytes(buf, offset, out, 1);
+
+    let iso_type = read_u32(buf, offset, little_endian);
+    let geometry_type = iso_type % 1000;
+    let (has_z, has_m) = match iso_type / 1000 {
+        0 => (false, false),
+        1 => (true, false),
+        2 => (false, true),
+        3 => (true, true),
+        _ => panic!("unexpected WKB geometry type {iso_type}"),
+    };
+
+    let mut ewkb_type = geometry_type;
+    if has_z {
+        ewkb_type |= EWKB_Z_FLAG;
+    }
+    if has_m {
+        ewkb_type |= EWKB_M_FLAG;
+    }
+    if srid.is_some() {
+        ewkb_type |= EWKB_SRID_FLAG;
+    }
+    write_u32(out, ewkb_type, little_endian);
+    if let Some(srid) = srid {
+        write_u32(out, srid as u32, little_endian);
+    }
+
+    let coord_size = 8 * (2 + has_z as usize + has_m as usize);
+    match geometry_type {
+        // Point
+        1 => copy_bytes(buf, offset, out, coord_size),
+        // LineString
+        2 => {
+            let num_coords = copy_u32(buf, offset, out, little_endian);
+            copy_bytes(buf, offset, out, num_coords * coord_size);
+        }
+        // Polygon
+        3 => {
+            let num_rings = copy_u32(buf, offset, out, little_endian);
+            for _ in 0..num_rings {
+                let num_coords = copy_u32(buf, offset, out, little_endian);
+                copy_bytes(buf, offset, out, num_coords * coord_size);
+            }
+        }
+        // MultiPoint, MultiLineString, MultiPolygon, GeometryCollection
+        4..=7 => {
+            let num_geometries = copy_u32(buf, offset, out, little_endian);
+            for _ in 0..num_geometries {
+                convert_geometry(buf, offset, out, None);
+            }
+        }
+        _ => panic!("unexpected WKB geometry type {iso_type}"),
+    }
+}
+
+fn read_u32(buf: &[u8], offset: &mut usize, little_endian: bool) -> u32 {
+    let bytes: [u8; 4] = buf[*offset..*offset + 4].try_into().unwrap();
+    *offset += 4;
+    if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, value: u32, little_endian: bool) {
+    if little_endian {
+        out.extend_from_slice(&value.to_le_bytes());
+    } else {
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Copy a u32 count from `buf` to `out`, returning its value.
+fn copy_u32(buf: &[u8], offset: &mut usize, out: &mut Vec<u8>, little_endian: bool) -> usize {
+    let value = read_u32(buf, offset, little_endian);
+    write_u32(out, value, little_endian);
+    value as usize
+}
+
+fn copy_bytes(buf: &[u8], offset: &mut usize, out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&buf[*offset..*offset + len]);
+    *offset += len;
+}
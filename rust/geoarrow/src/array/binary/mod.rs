@@ -2,9 +2,13 @@
 //! geometries.
 
 pub use array::WKBArray;
-pub use builder::WKBBuilder;
+pub use builder::{ByteOrder, WKBBuilder};
 pub use capacity::WKBCapacity;
+pub use dialect::WkbDialect;
+pub use summary::WkbSummary;
 
 mod array;
 mod builder;
 mod capacity;
+pub(crate) mod dialect;
+mod summary;
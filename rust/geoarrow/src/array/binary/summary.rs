@@ -0,0 +1,93 @@
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+/// Aggregate counts over the geometries of a [`WKBArray`][crate::array::WKBArray].
+///
+/// This is computed by [`WKBArray::summary`][crate::array::WKBArray::summary] without converting
+/// to a native array, and can be used to decide whether parsing an array is worthwhile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WkbSummary {
+    pub(crate) num_geometries: usize,
+    pub(crate) num_nulls: usize,
+    pub(crate) num_coords: usize,
+    pub(crate) max_parts: usize,
+}
+
+impl WkbSummary {
+    /// The number of non-null geometries.
+    pub fn num_geometries(&self) -> usize {
+        self.num_geometries
+    }
+
+    /// The number of null geometries.
+    pub fn num_nulls(&self) -> usize {
+        self.num_nulls
+    }
+
+    /// The total number of coordinates across all geometries.
+    pub fn num_coords(&self) -> usize {
+        self.num_coords
+    }
+
+    /// The largest number of parts in any one geometry.
+    ///
+    /// The parts of a multi geometry or geometry collection are its child geometries. Points, line
+    /// strings, and polygons have a single part, or none if they are empty.
+    pub fn max_parts(&self) -> usize {
+        self.max_parts
+    }
+
+    /// Add a geometry to this summary.
+    pub(crate) fn add_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) {
+        if let Some(geom) = geom {
+            self.num_geometries += 1;
+            self.num_coords += num_coords(geom);
+            self.max_parts = self.max_parts.max(num_parts(geom));
+        } else {
+            self.num_nulls += 1;
+        }
+    }
+}
+
+fn num_parts(geom: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(g) => g.coord().is_some() as usize,
+        LineString(g) => (g.num_coords() > 0) as usize,
+        Polygon(g) => g.exterior().is_some() as usize,
+        MultiPoint(g) => g.num_points(),
+        MultiLineString(g) => g.num_line_strings(),
+        MultiPolygon(g) => g.num_polygons(),
+        GeometryCollection(g) => g.num_geometries(),
+        Rect(_) | Triangle(_) | Line(_) => 1,
+    }
+}
+
+fn polygon_num_coords(polygon: &impl PolygonTrait<T = f64>) -> usize {
+    polygon
+        .exterior()
+        .into_iter()
+        .chain(polygon.interiors())
+        .map(|ring| ring.num_coords())
+        .sum()
+}
+
+fn num_coords(geom: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(g) => g.coord().is_some() as usize,
+        LineString(g) => g.num_coords(),
+        Polygon(g) => polygon_num_coords(g),
+        MultiPoint(g) => g.num_points(),
+        MultiLineString(g) => g.line_strings().map(|ls| ls.num_coords()).sum(),
+        MultiPolygon(g) => g.polygons().map(|p| polygon_num_coords(&p)).sum(),
+        GeometryCollection(g) => g.geometries().map(|child| num_coords(&child)).sum(),
+        Rect(_) => 2,
+        Triangle(_) => 3,
+        Line(_) => 2,
+    }
+}
@@ -46,6 +46,22 @@ impl CoordBuffer {
         }
     }
 
+    /// Returns `true` if this and `other` are backed by the same underlying allocations.
+    ///
+    /// This is a diagnostic aid to check whether an operation was zero-copy. Buffers with
+    /// different coordinate types never share an allocation.
+    pub fn shares_buffer_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CoordBuffer::Interleaved(left), CoordBuffer::Interleaved(right)) => {
+                left.shares_buffer_with(right)
+            }
+            (CoordBuffer::Separated(left), CoordBuffer::Separated(right)) => {
+                left.shares_buffer_with(right)
+            }
+            _ => false,
+        }
+    }
+
     pub fn coord_type(&self) -> CoordType {
         match self {
             CoordBuffer::Interleaved(cb) => cb.coord_type(),
@@ -98,30 +114,56 @@ impl CoordBuffer {
         coords
     }
 
-    pub fn into_coord_type(self, coord_type: CoordType) -> Self {
-        let dim = self.dim();
-        match (self, coord_type) {
-            (CoordBuffer::Interleaved(cb), CoordType::Interleaved) => CoordBuffer::Interleaved(cb),
-            (CoordBuffer::Interleaved(cb), CoordType::Separated) => {
-                let mut new_buffer = SeparatedCoordBufferBuilder::with_capacity(cb.len(), dim);
+    /// Convert this buffer to separated coordinates, keeping its dimension.
+    ///
+    /// This is a cheap clone if the buffer is already separated.
+    pub fn to_separated(&self) -> SeparatedCoordBuffer {
+        match self {
+            CoordBuffer::Separated(cb) => cb.clone(),
+            CoordBuffer::Interleaved(cb) => {
+                let mut new_buffer = SeparatedCoordBufferBuilder::with_capacity(cb.len(), cb.dim());
                 for i in 0..cb.len() {
-                    let coord = cb.value(i);
-                    new_buffer.push_coord(&coord);
+                    new_buffer.push_coord(&cb.value(i));
                 }
-                CoordBuffer::Separated(new_buffer.into())
+                new_buffer.into()
             }
-            (CoordBuffer::Separated(cb), CoordType::Separated) => CoordBuffer::Separated(cb),
-            (CoordBuffer::Separated(cb), CoordType::Interleaved) => {
-                let mut new_buffer = InterleavedCoordBufferBuilder::with_capacity(cb.len(), dim);
+        }
+    }
+
+    /// Convert this buffer to interleaved coordinates, keeping its dimension.
+    ///
+    /// This is a cheap clone if the buffer is already interleaved.
+    pub fn to_interleaved(&self) -> InterleavedCoordBuffer {
+        match self {
+            CoordBuffer::Interleaved(cb) => cb.clone(),
+            CoordBuffer::Separated(cb) => {
+                let mut new_buffer =
+                    InterleavedCoordBufferBuilder::with_capacity(cb.len(), cb.dim());
                 for i in 0..cb.len() {
-                    let coord = cb.value(i);
-                    new_buffer.push_coord(&coord);
+                    new_buffer.push_coord(&cb.value(i));
                 }
-                CoordBuffer::Interleaved(new_buffer.into())
+                new_buffer.into()
             }
         }
     }
 
+    /// Swap the x and y values of every coordinate, keeping any third dimension in place.
+    pub fn swap_xy(&self) -> Self {
+        match self {
+            CoordBuffer::Interleaved(cb) => CoordBuffer::Interleaved(cb.swap_xy()),
+            CoordBuffer::Separated(cb) => CoordBuffer::Separated(cb.swap_xy()),
+        }
+    }
+
+    pub fn into_coord_type(self, coord_type: CoordType) -> Self {
+        match (self, coord_type) {
+            (CoordBuffer::Interleaved(cb), CoordType::Interleaved) => CoordBuffer::Interleaved(cb),
+            (CoordBuffer::Separated(cb), CoordType::Separated) => CoordBuffer::Separated(cb),
+            (cb, CoordType::Separated) => CoordBuffer::Separated(cb.to_separated()),
+            (cb, CoordType::Interleaved) => CoordBuffer::Interleaved(cb.to_interleaved()),
+        }
+    }
+
     pub fn from_arrow(value: &dyn Array, dim: Dimension) -> Result<Self> {
         match value.data_type() {
             DataType::Struct(_) => {
@@ -261,4 +303,36 @@ mod test {
         assert_eq!(buf1, buf2);
         Ok(())
     }
+
+    #[test]
+    fn test_shares_buffer_with() -> Result<()> {
+        let interleaved = CoordBuffer::Interleaved(InterleavedCoordBuffer::from_vec(
+            vec![0., 3., 1., 4., 2., 5.],
+            Dimension::XY,
+        )?);
+        let separated = CoordBuffer::Separated((vec![0., 1., 2.], vec![3., 4., 5.]).try_into()?);
+
+        for buf in [interleaved, separated] {
+            assert!(buf.shares_buffer_with(&buf.clone()));
+            assert!(buf.shares_buffer_with(&buf.slice(1, 2)));
+            assert!(!buf.shares_buffer_with(&buf.owned_slice(1, 2)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn interleaved_to_separated() -> Result<()> {
+        let coords = vec![0., 3., 1., 4.];
+        let buf =
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::from_vec(coords, Dimension::XY)?);
+
+        let separated = buf.to_separated();
+        assert_eq!(separated.dim(), Dimension::XY);
+        assert_eq!(separated.buffers()[0].to_vec(), vec![0., 1.]);
+        assert_eq!(separated.buffers()[1].to_vec(), vec![3., 4.]);
+
+        let interleaved = CoordBuffer::Separated(separated).to_interleaved();
+        assert_eq!(interleaved.coords.to_vec(), vec![0., 3., 1., 4.]);
+        Ok(())
+    }
 }
@@ -109,6 +109,31 @@ impl CoordBufferBuilder {
         }
     }
 
+    /// Append all coordinates of `buffer` onto the end of this coordinate buffer.
+    ///
+    /// Coordinates are copied in bulk if `buffer` has the same coordinate type as this builder,
+    /// and converted one by one otherwise.
+    ///
+    /// ## Errors
+    ///
+    /// - If `buffer` does not have the same dimension as this coordinate buffer.
+    pub fn try_extend_from_buffer(&mut self, buffer: &CoordBuffer) -> Result<()> {
+        match (self, buffer) {
+            (CoordBufferBuilder::Interleaved(cb), CoordBuffer::Interleaved(buffer)) => {
+                cb.try_extend_from_buffer(buffer)
+            }
+            (CoordBufferBuilder::Separated(cb), CoordBuffer::Separated(buffer)) => {
+                cb.try_extend_from_buffer(buffer)
+            }
+            (cb, buffer) => {
+                for i in 0..buffer.len() {
+                    cb.try_push_coord(&buffer.value(i))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Push a valid coordinate with NaN values
     ///
     /// Used in the case of point and rect arrays, where a `null` array value still needs to have
@@ -61,6 +61,13 @@ impl InterleavedCoordBuffer {
         &self.coords
     }
 
+    /// Returns `true` if this and `other` are backed by the same underlying allocation.
+    ///
+    /// This is true for zero-copy slices of the same buffer, even if they don't overlap.
+    pub fn shares_buffer_with(&self, other: &Self) -> bool {
+        self.coords.inner().data_ptr() == other.coords.inner().data_ptr()
+    }
+
     pub fn values_array(&self) -> Float64Array {
         Float64Array::new(self.coords.clone(), None)
     }
@@ -73,6 +80,7 @@ impl InterleavedCoordBuffer {
         match self.dim {
             Dimension::XY => Field::new("xy", DataType::Float64, false),
             Dimension::XYZ => Field::new("xyz", DataType::Float64, false),
+            Dimension::XYM => Field::new("xym", DataType::Float64, false),
         }
     }
 
@@ -152,6 +160,18 @@ impl InterleavedCoordBuffer {
             dim,
         ))
     }
+
+    /// Swap the x and y values of every coordinate, keeping any third dimension in place.
+    pub fn swap_xy(&self) -> Self {
+        let mut coords = self.coords.to_vec();
+        coords
+            .chunks_exact_mut(self.dim.size())
+            .for_each(|coord| coord.swap(0, 1));
+        Self {
+            coords: coords.into(),
+            dim: self.dim,
+        }
+    }
 }
 
 impl IntoArrow for InterleavedCoordBuffer {
@@ -1,5 +1,6 @@
 use core::f64;
 
+use crate::array::coord::check_coord_dim;
 use crate::array::InterleavedCoordBuffer;
 use crate::datatypes::Dimension;
 use crate::error::{GeoArrowError, Result};
@@ -89,12 +90,7 @@ impl InterleavedCoordBufferBuilder {
     ///
     /// - If the added coordinate does not have the same dimension as the coordinate buffer.
     pub fn try_push_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
-        // TODO: should check xyz/zym
-        if coord.dim().size() != self.dim.size() {
-            return Err(GeoArrowError::General(
-                "coord dimension must match coord buffer dimension.".into(),
-            ));
-        }
+        check_coord_dim(coord.dim(), self.dim)?;
 
         self.coords.push(coord.x());
         self.coords.push(coord.y());
@@ -104,6 +100,21 @@ impl InterleavedCoordBufferBuilder {
         Ok(())
     }
 
+    /// Append all coordinates of `buffer` onto the end of this coordinate buffer.
+    ///
+    /// ## Errors
+    ///
+    /// - If `buffer` does not have the same dimension as this coordinate buffer.
+    pub fn try_extend_from_buffer(&mut self, buffer: &InterleavedCoordBuffer) -> Result<()> {
+        if buffer.dim() != self.dim {
+            return Err(GeoArrowError::General(
+                "coord dimension must match coord buffer dimension.".into(),
+            ));
+        }
+        self.coords.extend_from_slice(&buffer.coords);
+        Ok(())
+    }
+
     /// Push a valid coordinate with NaN values
     ///
     /// Used in the case of point and rect arrays, where a `null` array value still needs to have
@@ -4,6 +4,9 @@
 //! Coordinates can be either _interleaved_, where they're represented as a `FixedSizeList`, or
 //! _separated_, where they're represented with a `StructArray`.
 
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+
 mod combined;
 mod interleaved;
 mod separated;
@@ -26,3 +29,21 @@ pub enum CoordType {
     /// Separated coordinates.
     Separated,
 }
+
+/// Check that a coordinate with dimensions `coord_dim` can be stored in a buffer of `dim`.
+///
+/// Coordinates are matched by size, except that a Z coordinate is never stored in an XYM buffer,
+/// or a measure in an XYZ buffer.
+pub(crate) fn check_coord_dim(coord_dim: geo_traits::Dimensions, dim: Dimension) -> Result<()> {
+    let compatible = match (coord_dim, dim) {
+        (geo_traits::Dimensions::Xyz, Dimension::XYM)
+        | (geo_traits::Dimensions::Xym, Dimension::XYZ) => false,
+        (coord_dim, dim) => coord_dim.size() == dim.size(),
+    };
+    if !compatible {
+        return Err(GeoArrowError::General(
+            "coord dimension must match coord buffer dimension.".into(),
+        ));
+    }
+    Ok(())
+}
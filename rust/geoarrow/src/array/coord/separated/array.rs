@@ -24,7 +24,7 @@ pub struct SeparatedCoordBuffer {
 fn check(buffers: &[ScalarBuffer<f64>; 4], dim: Dimension) -> Result<()> {
     let all_same_length = match dim {
         Dimension::XY => buffers[0].len() == buffers[1].len(),
-        Dimension::XYZ => {
+        Dimension::XYZ | Dimension::XYM => {
             buffers[0].len() == buffers[1].len() && buffers[1].len() == buffers[2].len()
         }
     };
@@ -75,7 +75,7 @@ impl SeparatedCoordBuffer {
             Dimension::XY => {
                 vec![self.buffers[0].clone(), self.buffers[1].clone()]
             }
-            Dimension::XYZ => {
+            Dimension::XYZ | Dimension::XYM => {
                 vec![
                     self.buffers[0].clone(),
                     self.buffers[1].clone(),
@@ -97,7 +97,7 @@ impl SeparatedCoordBuffer {
                     Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
                 ]
             }
-            Dimension::XYZ => {
+            Dimension::XYZ | Dimension::XYM => {
                 vec![
                     Arc::new(Float64Array::new(self.buffers[0].clone(), None)),
                     Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
@@ -122,9 +122,29 @@ impl SeparatedCoordBuffer {
                     Field::new("z", DataType::Float64, false),
                 ]
             }
+            Dimension::XYM => {
+                vec![
+                    Field::new("x", DataType::Float64, false),
+                    Field::new("y", DataType::Float64, false),
+                    Field::new("m", DataType::Float64, false),
+                ]
+            }
         }
     }
 
+    /// Returns `true` if this and `other` are backed by the same underlying allocations.
+    ///
+    /// This is true for zero-copy slices of the same buffers, even if they don't overlap.
+    pub fn shares_buffer_with(&self, other: &Self) -> bool {
+        self.dim == other.dim
+            && self
+                .buffers
+                .iter()
+                .zip(other.buffers.iter())
+                .take(self.dim.size())
+                .all(|(left, right)| left.inner().data_ptr() == right.inner().data_ptr())
+    }
+
     pub fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
@@ -214,6 +234,18 @@ impl SeparatedCoordBuffer {
     pub fn from_coords<G: CoordTrait<T = f64>>(coords: &[G], dim: Dimension) -> Result<Self> {
         Ok(SeparatedCoordBufferBuilder::from_coords(coords, dim)?.into())
     }
+
+    /// Swap the x and y buffers, keeping any third dimension in place.
+    ///
+    /// This is zero-copy.
+    pub fn swap_xy(&self) -> Self {
+        let mut buffers = self.buffers.clone();
+        buffers.swap(0, 1);
+        Self {
+            buffers,
+            dim: self.dim,
+        }
+    }
 }
 
 impl IntoArrow for SeparatedCoordBuffer {
@@ -1,5 +1,6 @@
 use core::f64;
 
+use crate::array::coord::check_coord_dim;
 use crate::array::SeparatedCoordBuffer;
 use crate::datatypes::Dimension;
 use crate::error::{GeoArrowError, Result};
@@ -127,12 +128,7 @@ impl SeparatedCoordBufferBuilder {
     ///
     /// - If the added coordinate does not have the same dimension as the coordinate buffer.
     pub fn try_push_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
-        // TODO: should check xyz/zym
-        if coord.dim().size() != self.dim.size() {
-            return Err(GeoArrowError::General(
-                "coord dimension must match coord buffer dimension.".into(),
-            ));
-        }
+        check_coord_dim(coord.dim(), self.dim)?;
 
         self.buffers[0].push(coord.x());
         self.buffers[1].push(coord.y());
@@ -142,6 +138,23 @@ impl SeparatedCoordBufferBuilder {
         Ok(())
     }
 
+    /// Append all coordinates of `buffer` onto the end of this coordinate buffer.
+    ///
+    /// ## Errors
+    ///
+    /// - If `buffer` does not have the same dimension as this coordinate buffer.
+    pub fn try_extend_from_buffer(&mut self, buffer: &SeparatedCoordBuffer) -> Result<()> {
+        if buffer.dim() != self.dim {
+            return Err(GeoArrowError::General(
+                "coord dimension must match coord buffer dimension.".into(),
+            ));
+        }
+        for (builder, values) in self.buffers.iter_mut().zip(buffer.buffers()) {
+            builder.extend_from_slice(&values);
+        }
+        Ok(())
+    }
+
     /// Push a valid coordinate with NaN values
     ///
     /// Used in the case of point and rect arrays, where a `null` array value still needs to have
@@ -11,11 +11,57 @@ use crate::array::metadata::ArrayMetadata;
 use crate::array::wkt::WKTArray;
 use crate::array::CoordType;
 use crate::array::*;
-use crate::datatypes::{NativeType, SerializedType};
+use crate::datatypes::{Dimension, NativeType, SerializedType};
 use crate::error::Result;
+use crate::io::geozero::FromWKT;
+use crate::io::wkb::FromWKB;
 use crate::trait_::{NativeArrayRef, SerializedArray, SerializedArrayRef};
 use crate::{ArrayBase, NativeArray};
 
+/// Import an Arrow array as a native GeoArrow array, choosing the array type from `field`.
+///
+/// The GeoArrow extension name in the field metadata determines how the array is interpreted.
+/// Native types such as `geoarrow.point` are imported directly, while serialized types
+/// (`geoarrow.wkb`, `ogc.wkb`, and `geoarrow.wkt`) are parsed to the narrowest native array
+/// that can hold every geometry. Serialized input is parsed as XY geometries with the default
+/// coordinate type.
+///
+/// Fields without extension metadata are imported as points if their storage type is a
+/// `FixedSizeList` or `Struct`, or parsed as WKB or WKT if it is a binary or string type.
+pub fn from_arrow(array: &dyn Array, field: &Field) -> Result<Arc<dyn NativeArray>> {
+    let native_err = match NativeArrayDyn::from_arrow_array(array, field) {
+        Ok(arr) => return Ok(arr.into_inner()),
+        Err(err) => err,
+    };
+
+    // Only fall back to serialized types if the field is one, so that errors from importing a
+    // native array are preserved.
+    let Ok(serialized_type) = SerializedType::try_from(field) else {
+        return Err(native_err);
+    };
+
+    let coord_type = CoordType::default();
+    let dim = Dimension::XY;
+    match serialized_type {
+        SerializedType::WKB => {
+            FromWKB::from_wkb(&WKBArray::<i32>::try_from((array, field))?, coord_type, dim)
+        }
+        SerializedType::LargeWKB => {
+            FromWKB::from_wkb(&WKBArray::<i64>::try_from((array, field))?, coord_type, dim)
+        }
+        SerializedType::WKT => {
+            let arr = WKTArray::<i32>::try_from((array, field))?;
+            let metadata = arr.metadata();
+            FromWKT::from_wkt(&arr.into_inner(), coord_type, dim, metadata, false)
+        }
+        SerializedType::LargeWKT => {
+            let arr = WKTArray::<i64>::try_from((array, field))?;
+            let metadata = arr.metadata();
+            FromWKT::from_wkt(&arr.into_inner(), coord_type, dim, metadata, false)
+        }
+    }
+}
+
 /// A wrapper around a NativeArray of unknown type.
 #[derive(Debug, Clone)]
 #[repr(transparent)]
@@ -226,3 +272,44 @@ impl SerializedArray for SerializedArrayDyn {
         self.0.with_metadata(metadata)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkb::to_wkb;
+    use crate::test::point;
+
+    #[test]
+    fn from_arrow_wkb() {
+        let arr = point::point_array();
+        let wkb_arr = to_wkb::<i32>(&arr);
+        let field = wkb_arr.extension_field();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.wkb"
+        );
+
+        let parsed = from_arrow(wkb_arr.to_array_ref().as_ref(), &field).unwrap();
+        assert!(matches!(parsed.data_type(), NativeType::Point(_, _)));
+        assert_eq!(parsed.as_ref().as_point(), &arr);
+    }
+
+    #[test]
+    fn from_arrow_native() {
+        let arr = point::point_array();
+        let field = arr.extension_field();
+        let imported = from_arrow(arr.to_array_ref().as_ref(), &field).unwrap();
+        assert_eq!(imported.as_ref().as_point(), &arr);
+    }
+
+    #[test]
+    fn from_arrow_unknown_extension() {
+        let arr = point::point_array();
+        let field = arr
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_metadata([("ARROW:extension:name".to_string(), "foo".to_string())].into());
+        assert!(from_arrow(arr.to_array_ref().as_ref(), &field).is_err());
+    }
+}
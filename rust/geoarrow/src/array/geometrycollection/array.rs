@@ -8,7 +8,8 @@ use arrow_schema::{DataType, Field};
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::geometrycollection::{GeometryCollectionBuilder, GeometryCollectionCapacity};
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::offsets_buffer_i64_to_i32;
+use crate::array::mixed::builder::MixedGeometryBuilder;
+use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::{
     CoordBuffer, CoordType, LineStringArray, MixedGeometryArray, MultiLineStringArray,
     MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, WKBArray,
@@ -17,6 +18,7 @@ use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::{Geometry, GeometryCollection};
 use crate::trait_::{ArrayAccessor, GeometryArraySelfMethods, IntoArrow, NativeGeometryAccessor};
+use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::{ArrayBase, NativeArray};
 use geo_traits::GeometryCollectionTrait;
 
@@ -115,8 +117,37 @@ impl GeometryCollectionArray {
         }
     }
 
-    pub fn owned_slice(&self, _offset: usize, _length: usize) -> Self {
-        todo!()
+    pub fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        assert!(length >= 1, "length must be at least 1");
+
+        // Find the start and end of the geometries in the child array
+        let (start_geom_idx, _) = self.geom_offsets.start_end(offset);
+        let (_, end_geom_idx) = self.geom_offsets.start_end(offset + length - 1);
+
+        let geom_offsets = owned_slice_offsets(&self.geom_offsets, offset, length);
+
+        // The child array can't be sliced to length zero, so build an empty one if the selected
+        // collections contain no geometries.
+        let array = if end_geom_idx > start_geom_idx {
+            self.array
+                .owned_slice(start_geom_idx, end_geom_idx - start_geom_idx)
+        } else {
+            MixedGeometryBuilder::new_with_options(
+                self.dimension(),
+                self.coord_type(),
+                self.array.metadata(),
+                false,
+            )
+            .finish()
+        };
+
+        let validity = owned_slice_validity(self.nulls(), offset, length);
+
+        Self::new(array, geom_offsets, validity, self.metadata())
     }
 
     pub fn to_coord_type(&self, coord_type: CoordType) -> Self {
@@ -89,7 +89,11 @@ impl<'a> GeometryCollectionBuilder {
     /// speculatively avoid frequent reallocations. After calling `reserve`,
     /// capacity will be greater than or equal to `self.len() + additional`.
     /// Does nothing if capacity is already sufficient.
-    pub fn reserve(&mut self, additional: GeometryCollectionCapacity) {
+    ///
+    /// `additional` may also be a [`GeometryCapacity`][crate::array::GeometryCapacity], such as
+    /// one counted from arbitrary input geometries before they are pushed.
+    pub fn reserve(&mut self, additional: impl Into<GeometryCollectionCapacity>) {
+        let additional = additional.into();
         self.geoms.reserve(additional.mixed_capacity);
         self.geom_offsets.reserve(additional.geom_capacity);
     }
@@ -106,7 +110,8 @@ impl<'a> GeometryCollectionBuilder {
     /// minimal. Prefer [`reserve`] if future insertions are expected.
     ///
     /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(&mut self, additional: GeometryCollectionCapacity) {
+    pub fn reserve_exact(&mut self, additional: impl Into<GeometryCollectionCapacity>) {
+        let additional = additional.into();
         self.geoms.reserve_exact(additional.mixed_capacity);
         self.geom_offsets.reserve_exact(additional.geom_capacity);
     }
@@ -293,6 +298,16 @@ impl<'a> GeometryCollectionBuilder {
         Ok(())
     }
 
+    /// Add a valid but empty geometry collection (with no geometries) to the end of this array.
+    ///
+    /// Unlike [`push_null`][Self::push_null], this is a valid geometry, which is written as an
+    /// empty geometry when converted to WKB.
+    #[inline]
+    pub fn push_empty(&mut self) {
+        self.geom_offsets.try_push_usize(0).unwrap();
+        self.validity.append(true);
+    }
+
     #[inline]
     pub(crate) fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
@@ -505,3 +520,53 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for GeometryCollectio
         Self::from_wkb(&wkb_objects, dim, Default::default(), metadata, true)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::GeometryCapacity;
+    use crate::ArrayBase;
+    use geo::{line_string, point, Geometry, GeometryCollection};
+
+    #[test]
+    fn reserve_geometry_capacity() {
+        let geoms: Vec<Option<Geometry>> = vec![
+            Some(point!(x: 0., y: 1.).into()),
+            None,
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)].into()),
+            Some(
+                GeometryCollection::new_from(vec![
+                    point!(x: 3., y: 4.).into(),
+                    line_string![(x: 5., y: 5.), (x: 6., y: 6.)].into(),
+                ])
+                .into(),
+            ),
+        ];
+        let capacity = GeometryCapacity::from_geometries(geoms.iter().map(|g| g.as_ref())).unwrap();
+
+        let mut builder = GeometryCollectionBuilder::new(Dimension::XY);
+        builder.reserve(capacity);
+
+        let geom_offsets_capacity = builder.geom_offsets.capacity();
+        let point_coords_capacity = builder.geoms.points.coords.capacity();
+        let line_string_coords_capacity = builder.geoms.line_strings.coords.capacity();
+        assert!(geom_offsets_capacity >= geoms.len());
+        assert!(point_coords_capacity >= 2);
+        assert!(line_string_coords_capacity >= 5);
+
+        for geom in geoms.iter() {
+            builder.push_geometry(geom.as_ref()).unwrap();
+        }
+
+        assert_eq!(builder.geom_offsets.capacity(), geom_offsets_capacity);
+        assert_eq!(
+            builder.geoms.points.coords.capacity(),
+            point_coords_capacity
+        );
+        assert_eq!(
+            builder.geoms.line_strings.coords.capacity(),
+            line_string_coords_capacity
+        );
+        assert_eq!(builder.finish().len(), geoms.len());
+    }
+}
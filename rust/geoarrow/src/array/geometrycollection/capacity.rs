@@ -1,6 +1,6 @@
 use std::ops::Add;
 
-use crate::array::mixed::MixedCapacity;
+use crate::array::mixed::{GeometryCapacity, MixedCapacity};
 use crate::error::Result;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
@@ -36,6 +36,11 @@ impl GeometryCollectionCapacity {
         self.mixed_capacity.is_empty() && self.geom_capacity == 0
     }
 
+    /// The total number of coordinates across all geometries.
+    pub fn coord_capacity(&self) -> usize {
+        self.mixed_capacity.coord_capacity()
+    }
+
     #[inline]
     fn add_valid_point(&mut self, _geom: &impl PointTrait) {
         self.mixed_capacity.add_point();
@@ -163,3 +168,16 @@ impl Add for GeometryCollectionCapacity {
         Self::new(mixed_capacity, geom_capacity)
     }
 }
+
+impl From<GeometryCapacity> for GeometryCollectionCapacity {
+    /// Convert a [`GeometryCapacity`] into the capacity needed to store the same geometries in a
+    /// [`GeometryCollectionArray`][crate::array::GeometryCollectionArray].
+    ///
+    /// Each geometry that is not itself a geometry collection is stored as a collection of one.
+    fn from(value: GeometryCapacity) -> Self {
+        Self::new(
+            value.mixed + value.geometry_collection.mixed_capacity,
+            value.total_num_geoms(),
+        )
+    }
+}
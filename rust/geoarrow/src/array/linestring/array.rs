@@ -616,4 +616,18 @@ mod test {
 
         assert_eq!(linestring_arr, parsed_linestring_arr);
     }
+
+    #[test]
+    fn extend_from_sliced_array() {
+        let arr: LineStringArray = (vec![ls0(), ls1()].as_slice(), Dimension::XY).into();
+
+        let mut builder = crate::array::LineStringBuilder::new(Dimension::XY);
+        builder.push_line_string(Some(&ls0())).unwrap();
+        builder.extend_from_array(&arr.slice(1, 1)).unwrap();
+        let extended = builder.finish();
+
+        assert_eq!(extended.len(), 2);
+        assert_eq!(extended.value_as_geo(0), ls0());
+        assert_eq!(extended.value_as_geo(1), ls1());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::array::linestring::capacity::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::{extend_offsets, extend_validity};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -145,6 +146,16 @@ impl LineStringBuilder {
         Ok(())
     }
 
+    /// Add a valid but empty line string (with no coordinates) to the end of this array.
+    ///
+    /// Unlike [`push_null`][Self::push_null], this is a valid geometry, which is written as an
+    /// empty geometry when converted to WKB.
+    #[inline]
+    pub fn push_empty(&mut self) {
+        self.geom_offsets.try_push_usize(0).unwrap();
+        self.validity.append(true);
+    }
+
     #[inline]
     pub(crate) fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
@@ -155,6 +166,25 @@ impl LineStringBuilder {
         Arc::new(self.into_arrow())
     }
 
+    /// Append all line strings of `arr` onto the end of this builder.
+    ///
+    /// # Errors
+    ///
+    /// - If `arr` does not have the same dimension as this builder.
+    pub fn extend_from_array(&mut self, arr: &LineStringArray) -> Result<()> {
+        let coord_start = *arr.geom_offsets.first() as usize;
+        let coord_end = *arr.geom_offsets.last() as usize;
+        self.coords
+            .try_extend_from_buffer(&arr.coords.slice(coord_start, coord_end - coord_start))?;
+        extend_offsets(&mut self.geom_offsets, &arr.geom_offsets)?;
+        extend_validity(
+            &mut self.validity,
+            arr.validity.as_ref(),
+            arr.geom_offsets.len() - 1,
+        );
+        Ok(())
+    }
+
     pub fn finish(self) -> LineStringArray {
         self.into()
     }
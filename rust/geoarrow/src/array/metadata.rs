@@ -21,11 +21,34 @@ pub enum Edges {
     Spherical,
 }
 
+/// A coordinate reference system, in one of the representations allowed by the GeoArrow
+/// specification.
+///
+/// This pairs the `crs` and `crs_type` fields of [`ArrayMetadata`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Crs {
+    /// A [PROJJSON](https://proj.org/specifications/projjson.html) object.
+    ProjJson(Value),
+
+    /// A [WKT2:2019](https://www.ogc.org/publications/standard/wkt-crs/) string.
+    Wkt2(String),
+
+    /// An identifier in the form `AUTHORITY:CODE`, such as `EPSG:4326`.
+    AuthorityCode(String),
+
+    /// An opaque identifier that requires the consumer to communicate with the producer.
+    Srid(String),
+
+    /// A CRS whose representation is not specified, e.g. a PROJ string.
+    Unknown(Value),
+}
+
 /// A GeoArrow metadata object following the extension metadata [defined by the GeoArrow
 /// specification](https://geoarrow.org/extension-types).
 ///
 /// This is serialized to JSON when a [`geoarrow`](self) array is exported to an [`arrow`] array and
-/// deserialized when imported from an [`arrow`] array.
+/// deserialized when imported from an [`arrow`] array. Since it implements [`Serialize`] and
+/// [`Deserialize`], it can also be persisted with any serde format independently of Arrow.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ArrayMetadata {
     /// One of:
@@ -77,6 +100,38 @@ impl ArrayMetadata {
     pub fn should_serialize(&self) -> bool {
         self.crs.is_some() || self.edges.is_some()
     }
+
+    /// The CRS of this metadata, interpreted according to `crs_type`.
+    ///
+    /// A JSON object without a `crs_type` is read as PROJJSON, since no other representation is
+    /// a JSON object. Any other value without a recognized `crs_type` is returned as
+    /// [`Crs::Unknown`].
+    pub fn crs(&self) -> Option<Crs> {
+        let crs = self.crs.as_ref()?;
+        let crs = match (self.crs_type.as_deref(), crs) {
+            (Some("projjson"), _) | (None, Value::Object(_)) => Crs::ProjJson(crs.clone()),
+            (Some("wkt2:2019"), Value::String(wkt)) => Crs::Wkt2(wkt.clone()),
+            (Some("authority_code"), Value::String(code)) => Crs::AuthorityCode(code.clone()),
+            (Some("srid"), Value::String(srid)) => Crs::Srid(srid.clone()),
+            (Some("srid"), Value::Number(srid)) => Crs::Srid(srid.to_string()),
+            _ => Crs::Unknown(crs.clone()),
+        };
+        Some(crs)
+    }
+
+    /// Set the CRS of this metadata, along with the matching `crs_type`.
+    pub fn with_crs(mut self, crs: Crs) -> Self {
+        let (crs, crs_type) = match crs {
+            Crs::ProjJson(value) => (value, Some("projjson")),
+            Crs::Wkt2(wkt) => (Value::String(wkt), Some("wkt2:2019")),
+            Crs::AuthorityCode(code) => (Value::String(code), Some("authority_code")),
+            Crs::Srid(srid) => (Value::String(srid), Some("srid")),
+            Crs::Unknown(value) => (value, None),
+        };
+        self.crs = Some(crs);
+        self.crs_type = crs_type.map(String::from);
+        self
+    }
 }
 
 impl TryFrom<&Field> for ArrayMetadata {
@@ -90,3 +145,45 @@ impl TryFrom<&Field> for ArrayMetadata {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serde_round_trip() {
+        let metadata = ArrayMetadata {
+            crs: Some(json!({
+                "$schema": "https://proj.org/schemas/v0.7/projjson.schema.json",
+                "type": "GeographicCRS",
+                "name": "WGS 84",
+                "id": {"authority": "EPSG", "code": 4326}
+            })),
+            crs_type: Some("projjson".to_string()),
+            edges: Some(Edges::Spherical),
+        };
+
+        let serialized = serde_json::to_string(&metadata).unwrap();
+        let deserialized: ArrayMetadata = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, metadata);
+
+        let value = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(value["crs_type"], "projjson");
+        assert_eq!(value["edges"], "spherical");
+    }
+
+    #[test]
+    fn crs_accessor() {
+        let metadata =
+            ArrayMetadata::default().with_crs(Crs::AuthorityCode("EPSG:4326".to_string()));
+        assert_eq!(metadata.crs, Some(json!("EPSG:4326")));
+        assert_eq!(metadata.crs_type.as_deref(), Some("authority_code"));
+        assert_eq!(
+            metadata.crs(),
+            Some(Crs::AuthorityCode("EPSG:4326".to_string()))
+        );
+
+        assert_eq!(ArrayMetadata::default().crs(), None);
+    }
+}
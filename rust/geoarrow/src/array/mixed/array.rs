@@ -281,8 +281,25 @@ impl MixedGeometryArray {
         }
     }
 
-    pub fn owned_slice(&self, _offset: usize, _length: usize) -> Self {
-        todo!()
+    pub fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        assert!(length >= 1, "length must be at least 1");
+
+        // The children of a dense union are not sorted by position, so rebuild from the geometries
+        // rather than slicing each child.
+        let mut builder = MixedGeometryBuilder::new_with_options(
+            self.dimension(),
+            self.coord_type(),
+            self.metadata(),
+            false,
+        );
+        for i in offset..offset + length {
+            builder.push_geometry(Some(&self.value(i))).unwrap();
+        }
+        builder.finish()
     }
 
     pub fn to_coord_type(&self, coord_type: CoordType) -> Self {
@@ -416,6 +433,15 @@ impl NativeGeometryAccessor for MixedGeometryArray {
             17 => {
                 panic!("nested geometry collections not supported")
             }
+            21 => Geometry::Point(self.points.value(offset)),
+            22 => Geometry::LineString(self.line_strings.value(offset)),
+            23 => Geometry::Polygon(self.polygons.value(offset)),
+            24 => Geometry::MultiPoint(self.multi_points.value(offset)),
+            25 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
+            26 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
+            27 => {
+                panic!("nested geometry collections not supported")
+            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
@@ -449,6 +475,15 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MixedGeometryArray {
             17 => {
                 panic!("nested geometry collections not supported")
             }
+            21 => Geometry::Point(self.points.value(offset)),
+            22 => Geometry::LineString(self.line_strings.value(offset)),
+            23 => Geometry::Polygon(self.polygons.value(offset)),
+            24 => Geometry::MultiPoint(self.multi_points.value(offset)),
+            25 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
+            26 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
+            27 => {
+                panic!("nested geometry collections not supported")
+            }
             _ => panic!("unknown type_id {}", type_id),
         };
 
@@ -483,6 +518,15 @@ impl<'a> ArrayAccessor<'a> for MixedGeometryArray {
             17 => {
                 panic!("nested geometry collections not supported")
             }
+            21 => Geometry::Point(self.points.value(offset)),
+            22 => Geometry::LineString(self.line_strings.value(offset)),
+            23 => Geometry::Polygon(self.polygons.value(offset)),
+            24 => Geometry::MultiPoint(self.multi_points.value(offset)),
+            25 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
+            26 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
+            27 => {
+                panic!("nested geometry collections not supported")
+            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
@@ -537,6 +581,8 @@ impl TryFrom<&UnionArray> for MixedGeometryArray {
                         Dimension::XY
                     } else if type_id < 20 {
                         Dimension::XYZ
+                    } else if type_id < 30 {
+                        Dimension::XYM
                     } else {
                         return Err(GeoArrowError::General(format!(
                             "Unsupported type_id: {}",
@@ -545,42 +591,42 @@ impl TryFrom<&UnionArray> for MixedGeometryArray {
                     };
 
                     match type_id {
-                        1 | 11 => {
+                        1 | 11 | 21 => {
                             points.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
                                     .unwrap(),
                             );
                         }
-                        2 | 12 => {
+                        2 | 12 | 22 => {
                             line_strings.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
                                     .unwrap(),
                             );
                         }
-                        3 | 13 => {
+                        3 | 13 | 23 => {
                             polygons.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
                                     .unwrap(),
                             );
                         }
-                        4 | 14 => {
+                        4 | 14 | 24 => {
                             multi_points.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
                                     .unwrap(),
                             );
                         }
-                        5 | 15 => {
+                        5 | 15 | 25 => {
                             multi_line_strings.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
                                     .unwrap(),
                             );
                         }
-                        6 | 16 => {
+                        6 | 16 | 26 => {
                             multi_polygons.push(
                                 (value.child(type_id).as_ref(), dimension)
                                     .try_into()
@@ -685,6 +731,7 @@ impl From<PointArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![1; value.len()],
             Dimension::XYZ => vec![11; value.len()],
+            Dimension::XYM => vec![21; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
@@ -706,6 +753,7 @@ impl From<LineStringArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![2; value.len()],
             Dimension::XYZ => vec![12; value.len()],
+            Dimension::XYM => vec![22; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
@@ -727,6 +775,7 @@ impl From<PolygonArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![3; value.len()],
             Dimension::XYZ => vec![13; value.len()],
+            Dimension::XYM => vec![23; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
@@ -748,6 +797,7 @@ impl From<MultiPointArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![4; value.len()],
             Dimension::XYZ => vec![14; value.len()],
+            Dimension::XYM => vec![24; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
@@ -769,6 +819,7 @@ impl From<MultiLineStringArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![5; value.len()],
             Dimension::XYZ => vec![15; value.len()],
+            Dimension::XYM => vec![25; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
@@ -790,6 +841,7 @@ impl From<MultiPolygonArray> for MixedGeometryArray {
         let type_ids = match value.dimension() {
             Dimension::XY => vec![6; value.len()],
             Dimension::XYZ => vec![16; value.len()],
+            Dimension::XYM => vec![26; value.len()],
         };
         let metadata = value.metadata.clone();
         Self::new(
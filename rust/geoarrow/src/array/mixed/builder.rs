@@ -272,6 +272,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(1),
             Dimension::XYZ => self.types.push(11),
+            Dimension::XYM => self.types.push(21),
         }
     }
 
@@ -304,6 +305,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(2),
             Dimension::XYZ => self.types.push(12),
+            Dimension::XYM => self.types.push(22),
         }
     }
 
@@ -332,6 +334,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(3),
             Dimension::XYZ => self.types.push(13),
+            Dimension::XYM => self.types.push(23),
         }
     }
 
@@ -356,6 +359,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(4),
             Dimension::XYZ => self.types.push(14),
+            Dimension::XYM => self.types.push(24),
         }
     }
 
@@ -380,6 +384,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(5),
             Dimension::XYZ => self.types.push(15),
+            Dimension::XYM => self.types.push(25),
         }
     }
 
@@ -404,6 +409,7 @@ impl<'a> MixedGeometryBuilder {
         match self.dim {
             Dimension::XY => self.types.push(6),
             Dimension::XYZ => self.types.push(16),
+            Dimension::XYM => self.types.push(26),
         }
     }
 
@@ -1,5 +1,6 @@
 use std::ops::Add;
 
+use crate::array::geometrycollection::GeometryCollectionCapacity;
 use crate::array::linestring::LineStringCapacity;
 use crate::array::multilinestring::MultiLineStringCapacity;
 use crate::array::multipoint::MultiPointCapacity;
@@ -75,6 +76,16 @@ impl MixedCapacity {
         total
     }
 
+    /// The total number of coordinates across all child arrays.
+    pub fn coord_capacity(&self) -> usize {
+        self.point
+            + self.line_string.coord_capacity()
+            + self.polygon.coord_capacity()
+            + self.multi_point.coord_capacity()
+            + self.multi_line_string.coord_capacity()
+            + self.multi_polygon.coord_capacity()
+    }
+
     pub fn point_capacity(&self) -> usize {
         self.point
     }
@@ -249,3 +260,114 @@ impl Add for MixedCapacity {
         )
     }
 }
+
+/// A counter for the buffer sizes of an array of arbitrary geometries.
+///
+/// In contrast to [`MixedCapacity`], this also counts null values and geometry collections, so it
+/// can be computed in a single pass over any input and then used to size whichever builder fits
+/// the data.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GeometryCapacity {
+    pub(crate) nulls: usize,
+    pub(crate) mixed: MixedCapacity,
+    pub(crate) geometry_collection: GeometryCollectionCapacity,
+}
+
+impl GeometryCapacity {
+    /// Create a new capacity with known sizes.
+    pub fn new(
+        nulls: usize,
+        mixed: MixedCapacity,
+        geometry_collection: GeometryCollectionCapacity,
+    ) -> Self {
+        Self {
+            nulls,
+            mixed,
+            geometry_collection,
+        }
+    }
+
+    /// Create a new empty capacity.
+    pub fn new_empty() -> Self {
+        Self::new(
+            0,
+            MixedCapacity::new_empty(),
+            GeometryCollectionCapacity::new_empty(),
+        )
+    }
+
+    /// Return `true` if the capacity is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nulls == 0 && self.mixed.is_empty() && self.geometry_collection.is_empty()
+    }
+
+    /// The number of null geometries.
+    pub fn null_count(&self) -> usize {
+        self.nulls
+    }
+
+    /// The capacity of all non-null geometries that are not geometry collections.
+    pub fn mixed_capacity(&self) -> MixedCapacity {
+        self.mixed
+    }
+
+    /// The capacity of all geometry collections.
+    pub fn geometry_collection_capacity(&self) -> GeometryCollectionCapacity {
+        self.geometry_collection
+    }
+
+    /// The total number of geometries, nulls included.
+    pub fn total_num_geoms(&self) -> usize {
+        self.nulls + self.mixed.total_num_geoms() + self.geometry_collection.geom_capacity
+    }
+
+    /// Add a Geometry to this capacity counter.
+    #[inline]
+    pub fn add_geometry(&mut self, geom: Option<&impl GeometryTrait>) -> Result<()> {
+        if let Some(geom) = geom {
+            match geom.as_type() {
+                geo_traits::GeometryType::GeometryCollection(g) => {
+                    self.geometry_collection.add_geometry_collection(Some(g))?
+                }
+                _ => self.mixed.add_geometry(Some(geom))?,
+            }
+        } else {
+            self.nulls += 1;
+        }
+        Ok(())
+    }
+
+    /// Create a capacity counter from an iterator of Geometries.
+    pub fn from_geometries<'a>(
+        geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+    ) -> Result<Self> {
+        let mut counter = Self::new_empty();
+        for maybe_geom in geoms.into_iter() {
+            counter.add_geometry(maybe_geom)?;
+        }
+        Ok(counter)
+    }
+
+    /// Create a capacity counter from an iterator of owned Geometries.
+    pub fn from_owned_geometries<'a>(
+        geoms: impl Iterator<Item = Option<(impl GeometryTrait + 'a)>>,
+    ) -> Result<Self> {
+        let mut counter = Self::new_empty();
+        for maybe_geom in geoms.into_iter() {
+            counter.add_geometry(maybe_geom.as_ref())?;
+        }
+        Ok(counter)
+    }
+}
+
+impl Add for GeometryCapacity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.nulls + rhs.nulls,
+            self.mixed + rhs.mixed,
+            self.geometry_collection + rhs.geometry_collection,
+        )
+    }
+}
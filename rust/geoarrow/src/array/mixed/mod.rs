@@ -1,6 +1,6 @@
 pub use array::MixedGeometryArray;
 pub use builder::MixedGeometryBuilder;
-pub use capacity::MixedCapacity;
+pub use capacity::{GeometryCapacity, MixedCapacity};
 
 pub(crate) mod array;
 pub(crate) mod builder;
@@ -2,18 +2,18 @@
 
 #![allow(missing_docs)] // FIXME
 
-pub use binary::{WKBArray, WKBBuilder, WKBCapacity};
+pub use binary::{ByteOrder, WKBArray, WKBBuilder, WKBCapacity, WkbDialect, WkbSummary};
 pub use cast::{AsChunkedNativeArray, AsNativeArray, AsSerializedArray};
 pub use coord::{
     CoordBuffer, CoordBufferBuilder, CoordType, InterleavedCoordBuffer,
     InterleavedCoordBufferBuilder, SeparatedCoordBuffer, SeparatedCoordBufferBuilder,
 };
-pub use dynamic::{NativeArrayDyn, SerializedArrayDyn};
+pub use dynamic::{from_arrow, NativeArrayDyn, SerializedArrayDyn};
 pub use geometrycollection::{
     GeometryCollectionArray, GeometryCollectionBuilder, GeometryCollectionCapacity,
 };
 pub use linestring::{LineStringArray, LineStringBuilder, LineStringCapacity};
-pub use mixed::{MixedCapacity, MixedGeometryArray, MixedGeometryBuilder};
+pub use mixed::{GeometryCapacity, MixedCapacity, MixedGeometryArray, MixedGeometryBuilder};
 pub use multilinestring::{MultiLineStringArray, MultiLineStringBuilder, MultiLineStringCapacity};
 pub use multipoint::{MultiPointArray, MultiPointBuilder, MultiPointCapacity};
 pub use multipolygon::{MultiPolygonArray, MultiPolygonBuilder, MultiPolygonCapacity};
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipoint::MultiPointCapacity;
+use crate::array::util::{extend_offsets, extend_validity};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -140,6 +141,25 @@ impl MultiPointBuilder {
         Arc::new(self.into_arrow())
     }
 
+    /// Append all multi points of `arr` onto the end of this builder.
+    ///
+    /// # Errors
+    ///
+    /// - If `arr` does not have the same dimension as this builder.
+    pub fn extend_from_array(&mut self, arr: &MultiPointArray) -> Result<()> {
+        let coord_start = *arr.geom_offsets.first() as usize;
+        let coord_end = *arr.geom_offsets.last() as usize;
+        self.coords
+            .try_extend_from_buffer(&arr.coords.slice(coord_start, coord_end - coord_start))?;
+        extend_offsets(&mut self.geom_offsets, &arr.geom_offsets)?;
+        extend_validity(
+            &mut self.validity,
+            arr.validity.as_ref(),
+            arr.geom_offsets.len() - 1,
+        );
+        Ok(())
+    }
+
     pub fn finish(self) -> MultiPointArray {
         self.into()
     }
@@ -289,6 +309,16 @@ impl MultiPointBuilder {
         Ok(())
     }
 
+    /// Add a valid but empty multi point (with no points) to the end of this array.
+    ///
+    /// Unlike [`push_null`][Self::push_null], this is a valid geometry, which is written as an
+    /// empty geometry when converted to WKB.
+    #[inline]
+    pub fn push_empty(&mut self) {
+        self.geom_offsets.try_push_usize(0).unwrap();
+        self.validity.append(true);
+    }
+
     #[inline]
     pub(crate) fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
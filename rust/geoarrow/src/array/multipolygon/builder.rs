@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
+use crate::array::util::{extend_offsets, extend_validity};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -177,6 +178,37 @@ impl MultiPolygonBuilder {
         Arc::new(self.into_arrow())
     }
 
+    /// Append all multi polygons of `arr` onto the end of this builder.
+    ///
+    /// # Errors
+    ///
+    /// - If `arr` does not have the same dimension as this builder.
+    pub fn extend_from_array(&mut self, arr: &MultiPolygonArray) -> Result<()> {
+        let polygon_start = *arr.geom_offsets.first() as usize;
+        let polygon_end = *arr.geom_offsets.last() as usize;
+        let polygon_offsets = arr
+            .polygon_offsets
+            .slice(polygon_start, polygon_end - polygon_start);
+
+        let ring_start = *polygon_offsets.first() as usize;
+        let ring_end = *polygon_offsets.last() as usize;
+        let ring_offsets = arr.ring_offsets.slice(ring_start, ring_end - ring_start);
+
+        let coord_start = *ring_offsets.first() as usize;
+        let coord_end = *ring_offsets.last() as usize;
+        self.coords
+            .try_extend_from_buffer(&arr.coords.slice(coord_start, coord_end - coord_start))?;
+        extend_offsets(&mut self.geom_offsets, &arr.geom_offsets)?;
+        extend_offsets(&mut self.polygon_offsets, &polygon_offsets)?;
+        extend_offsets(&mut self.ring_offsets, &ring_offsets)?;
+        extend_validity(
+            &mut self.validity,
+            arr.validity.as_ref(),
+            arr.geom_offsets.len() - 1,
+        );
+        Ok(())
+    }
+
     pub fn finish(self) -> MultiPolygonArray {
         self.into()
     }
@@ -399,8 +431,12 @@ impl MultiPolygonBuilder {
         Ok(())
     }
 
+    /// Add a valid but empty multi polygon (with no polygons) to the end of this array.
+    ///
+    /// Unlike [`push_null`][Self::push_null], this is a valid geometry, which is written as an
+    /// empty geometry when converted to WKB.
     #[inline]
-    pub(crate) fn push_empty(&mut self) {
+    pub fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
         self.validity.append(true);
     }
@@ -531,4 +531,31 @@ mod test {
 
         assert_eq!(geom_arr, parsed_geom_arr);
     }
+
+    #[test]
+    fn dimension() {
+        assert_eq!(crate::test::point::point_array().dimension(), Dimension::XY);
+        assert_eq!(
+            crate::test::point::point_z_array().dimension(),
+            Dimension::XYZ
+        );
+    }
+
+    #[test]
+    fn extend_from_array() {
+        let arr: PointArray = (vec![p0(), p1(), p2()].as_slice(), Dimension::XY).into();
+
+        let mut builder = crate::array::PointBuilder::new(Dimension::XY);
+        builder.push_point(Some(&p2()));
+        builder.extend_from_array(&arr).unwrap();
+        builder.push_null();
+        let extended = builder.finish();
+
+        let expected: PointArray = (
+            vec![Some(p2()), Some(p0()), Some(p1()), Some(p2()), None],
+            Dimension::XY,
+        )
+            .into();
+        assert_eq!(extended, expected);
+    }
 }
@@ -2,6 +2,7 @@ use core::f64;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::extend_validity;
 // use super::array::check;
 use crate::array::{
     CoordBufferBuilder, CoordType, InterleavedCoordBufferBuilder, PointArray,
@@ -120,6 +121,17 @@ impl PointBuilder {
         (self.coords, self.validity)
     }
 
+    /// Append all points of `arr` onto the end of this builder.
+    ///
+    /// # Errors
+    ///
+    /// - If `arr` does not have the same dimension as this builder.
+    pub fn extend_from_array(&mut self, arr: &PointArray) -> Result<()> {
+        self.coords.try_extend_from_buffer(&arr.coords)?;
+        extend_validity(&mut self.validity, arr.validity.as_ref(), arr.coords.len());
+        Ok(())
+    }
+
     pub fn finish(self) -> PointArray {
         self.into()
     }
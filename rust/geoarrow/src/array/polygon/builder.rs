@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::{extend_offsets, extend_validity};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::polygon::PolygonCapacity;
@@ -204,6 +205,30 @@ impl PolygonBuilder {
         Ok(())
     }
 
+    /// Append all polygons of `arr` onto the end of this builder.
+    ///
+    /// # Errors
+    ///
+    /// - If `arr` does not have the same dimension as this builder.
+    pub fn extend_from_array(&mut self, arr: &PolygonArray) -> Result<()> {
+        let ring_start = *arr.geom_offsets.first() as usize;
+        let ring_end = *arr.geom_offsets.last() as usize;
+        let ring_offsets = arr.ring_offsets.slice(ring_start, ring_end - ring_start);
+
+        let coord_start = *ring_offsets.first() as usize;
+        let coord_end = *ring_offsets.last() as usize;
+        self.coords
+            .try_extend_from_buffer(&arr.coords.slice(coord_start, coord_end - coord_start))?;
+        extend_offsets(&mut self.geom_offsets, &arr.geom_offsets)?;
+        extend_offsets(&mut self.ring_offsets, &ring_offsets)?;
+        extend_validity(
+            &mut self.validity,
+            arr.validity.as_ref(),
+            arr.geom_offsets.len() - 1,
+        );
+        Ok(())
+    }
+
     pub fn finish(self) -> PolygonArray {
         self.into()
     }
@@ -232,6 +257,9 @@ impl PolygonBuilder {
 
     /// Add a new Polygon to the end of this array.
     ///
+    /// This accepts any [`PolygonTrait`] implementer, so polygons of other libraries or of your
+    /// own types can be pushed without first converting them to [`geo::Polygon`].
+    ///
     /// # Errors
     ///
     /// This function errors iff the new last item is larger than what O supports.
@@ -366,8 +394,12 @@ impl PolygonBuilder {
         Ok(())
     }
 
+    /// Add a valid but empty polygon (with no rings) to the end of this array.
+    ///
+    /// Unlike [`push_null`][Self::push_null], this is a valid geometry, which is written as an
+    /// empty geometry when converted to WKB.
     #[inline]
-    pub(crate) fn push_empty(&mut self) {
+    pub fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
         self.validity.append(true);
     }
@@ -559,3 +591,68 @@ impl From<PolygonBuilder> for MultiLineStringBuilder {
         .unwrap()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+    use geo_traits::Dimensions;
+
+    /// An axis-aligned square that is not backed by any geometry library.
+    struct Square {
+        min_x: f64,
+        min_y: f64,
+        size: f64,
+    }
+
+    impl PolygonTrait for Square {
+        type T = f64;
+        type RingType<'a>
+            = geo::LineString
+        where
+            Self: 'a;
+
+        fn dim(&self) -> Dimensions {
+            Dimensions::Xy
+        }
+
+        fn exterior(&self) -> Option<Self::RingType<'_>> {
+            let (x, y, size) = (self.min_x, self.min_y, self.size);
+            Some(geo::LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]))
+        }
+
+        fn num_interiors(&self) -> usize {
+            0
+        }
+
+        unsafe fn interior_unchecked(&self, _i: usize) -> Self::RingType<'_> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn push_custom_polygon_trait() {
+        let square = Square {
+            min_x: 1.,
+            min_y: 2.,
+            size: 3.,
+        };
+        let mut builder = PolygonBuilder::new(Dimension::XY);
+        builder.push_polygon(Some(&square)).unwrap();
+        let arr = builder.finish();
+
+        let expected = geo::polygon![
+            (x: 1., y: 2.),
+            (x: 4., y: 2.),
+            (x: 4., y: 5.),
+            (x: 1., y: 5.),
+        ];
+        assert_eq!(arr.value_as_geo(0), expected);
+    }
+}
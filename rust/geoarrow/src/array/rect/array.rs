@@ -218,7 +218,7 @@ impl TryFrom<(&StructArray, Dimension)> for RectArray {
             Dimension::XY => {
                 core::array::from_fn(|i| columns[i].as_primitive::<Float64Type>().values().clone())
             }
-            Dimension::XYZ => {
+            Dimension::XYZ | Dimension::XYM => {
                 core::array::from_fn(|i| columns[i].as_primitive::<Float64Type>().values().clone())
             }
         };
@@ -226,7 +226,7 @@ impl TryFrom<(&StructArray, Dimension)> for RectArray {
             Dimension::XY => {
                 core::array::from_fn(|i| columns[i].as_primitive::<Float64Type>().values().clone())
             }
-            Dimension::XYZ => {
+            Dimension::XYZ | Dimension::XYM => {
                 core::array::from_fn(|i| columns[i].as_primitive::<Float64Type>().values().clone())
             }
         };
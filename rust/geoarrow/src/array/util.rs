@@ -1,8 +1,9 @@
 //! Note: This entire mod is a candidate to upstream into arrow-rs.
 
 use arrow_array::OffsetSizeTrait;
-use arrow_buffer::OffsetBuffer;
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer};
 
+use crate::array::offset_builder::OffsetsBuilder;
 use crate::error::Result;
 
 pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
@@ -55,6 +56,28 @@ pub(crate) fn offset_lengths<O: OffsetSizeTrait>(
         .map(|w| (w[1] - w[0]).to_usize().unwrap())
 }
 
+/// Append the offsets of `offsets` onto `builder`, rebased to the end of `builder`.
+///
+/// `offsets` may be sliced, i.e. it does not need to start at zero.
+pub(crate) fn extend_offsets(
+    builder: &mut OffsetsBuilder<i32>,
+    offsets: &OffsetBuffer<i32>,
+) -> Result<()> {
+    builder.try_extend_from_lengths(offset_lengths(offsets))
+}
+
+/// Append `length` validity bits onto `builder`, treating missing validity as all valid.
+pub(crate) fn extend_validity(
+    builder: &mut NullBufferBuilder,
+    validity: Option<&NullBuffer>,
+    length: usize,
+) {
+    match validity {
+        Some(validity) => validity.iter().for_each(|valid| builder.append(valid)),
+        None => builder.append_n_non_nulls(length),
+    }
+}
+
 /// Offsets utils that I miss from arrow2
 pub(crate) trait OffsetBufferUtils<O: OffsetSizeTrait> {
     /// Returns the length an array with these offsets would be.
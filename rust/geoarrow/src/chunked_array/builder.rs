@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use geo_traits::GeometryTrait;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::CoordType;
+use crate::chunked_array::{ChunkedNativeArray, ChunkedNativeArrayDyn};
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayBuilder;
+use crate::{ArrayBase, NativeArray};
+
+/// A builder that streams geometries into a chunked array of fixed-size chunks.
+///
+/// Geometries are pushed into an inner builder of type `B`. Once it holds `chunk_size` rows, it is
+/// finished and stored as a chunk, and a new inner builder is started. The final chunk may be
+/// shorter than `chunk_size`.
+#[derive(Debug)]
+pub struct ChunkedGeometryBuilder<B: GeometryArrayBuilder> {
+    dim: Dimension,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+    chunk_size: usize,
+    builder: B,
+    chunks: Vec<Arc<dyn NativeArray>>,
+}
+
+impl<B: GeometryArrayBuilder> ChunkedGeometryBuilder<B> {
+    /// Creates a new builder emitting chunks of `chunk_size` rows.
+    pub fn new(dim: Dimension, chunk_size: usize) -> Result<Self> {
+        Self::new_with_options(dim, chunk_size, Default::default(), Default::default())
+    }
+
+    /// Creates a new builder emitting chunks of `chunk_size` rows with the given options.
+    pub fn new_with_options(
+        dim: Dimension,
+        chunk_size: usize,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Result<Self> {
+        if chunk_size == 0 {
+            return Err(GeoArrowError::General(
+                "chunk_size must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            dim,
+            coord_type,
+            metadata: metadata.clone(),
+            chunk_size,
+            builder: B::with_geom_capacity_and_options(dim, chunk_size, coord_type, metadata),
+            chunks: vec![],
+        })
+    }
+
+    /// Returns the total number of rows pushed so far, across all chunks.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.len()).sum::<usize>() + self.builder.len()
+    }
+
+    /// Returns whether no rows have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Push a geometry, starting a new chunk if the current one is full.
+    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+        if self.builder.len() == self.chunk_size {
+            self.flush();
+        }
+        self.builder.push_geometry(value)
+    }
+
+    fn flush(&mut self) {
+        let builder = std::mem::replace(
+            &mut self.builder,
+            B::with_geom_capacity_and_options(
+                self.dim,
+                self.chunk_size,
+                self.coord_type,
+                self.metadata.clone(),
+            ),
+        );
+        self.chunks.push(builder.finish());
+    }
+
+    /// Finishes building and returns the chunked array.
+    ///
+    /// If no geometries were pushed, the output has a single empty chunk.
+    pub fn finish(mut self) -> Result<Arc<dyn ChunkedNativeArray>> {
+        if !self.builder.is_empty() || self.chunks.is_empty() {
+            self.chunks.push(self.builder.finish());
+        }
+
+        let chunk_refs = self
+            .chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsChunkedNativeArray, PointBuilder};
+
+    #[test]
+    fn chunks_of_fixed_size() {
+        let mut builder = ChunkedGeometryBuilder::<PointBuilder>::new(Dimension::XY, 2).unwrap();
+        for i in 0..5 {
+            let point = geo::Geometry::Point(geo::point!(x: i as f64, y: 0.));
+            builder.push_geometry(Some(&point)).unwrap();
+        }
+        assert_eq!(builder.len(), 5);
+
+        let chunked = builder.finish().unwrap();
+        let lengths = chunked
+            .as_ref()
+            .as_point()
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.len())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2, 1]);
+    }
+}
@@ -7,9 +7,11 @@
 //! Additionally, if the `rayon` feature is active, operations on chunked arrays will automatically
 //! be parallelized across each chunk.
 
+mod builder;
 #[allow(missing_docs)] // FIXME
 mod dynamic;
 
+pub use builder::ChunkedGeometryBuilder;
 pub use dynamic::ChunkedNativeArrayDyn;
 
 use std::any::Any;
@@ -22,6 +24,7 @@ use arrow_schema::{DataType, Field};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+use crate::algorithm::native::Concatenate;
 use crate::array::*;
 use crate::datatypes::NativeType;
 use crate::error::{GeoArrowError, Result};
@@ -778,6 +781,31 @@ pub trait ChunkedNativeArray: ChunkedArrayBase {
     /// ```
     fn as_ref(&self) -> &dyn ChunkedNativeArray;
 
+    /// Concatenates all chunks into a single contiguous array.
+    ///
+    /// Returns an error if the chunks together hold more geometries or coordinates than can be
+    /// indexed by `i32` offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{
+    ///     chunked_array::{ChunkedGeometryArray, ChunkedNativeArray},
+    ///     array::PointArray
+    /// };
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::ArrayBase;
+    ///
+    /// let array_0: PointArray = (vec![&geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+    /// let array_1: PointArray = (vec![&geo::point!(x: 3., y: 4.)].as_slice(), Dimension::XY).into();
+    /// let chunked_array = ChunkedGeometryArray::new(vec![array_0, array_1]);
+    /// let array = chunked_array.into_single().unwrap();
+    /// assert_eq!(array.len(), 2);
+    /// ```
+    fn into_single(&self) -> Result<Arc<dyn NativeArray>> {
+        self.as_ref().concatenate()
+    }
+
     /// Returns a zero-copy slice of this array with the indicated offset and length.
     fn slice(&self, mut offset: usize, mut length: usize) -> Result<Arc<dyn ChunkedNativeArray>> {
         if offset + length > self.len() {
@@ -27,6 +27,11 @@ pub enum Dimension {
 
     /// Three-dimensional.
     XYZ,
+
+    /// Two-dimensional with a measure value.
+    ///
+    /// The measure is stored as the third coordinate, which is named `m` rather than `z`.
+    XYM,
 }
 
 impl Dimension {
@@ -39,11 +44,12 @@ impl Dimension {
     ///
     /// assert_eq!(Dimension::XY.size(), 2);
     /// assert_eq!(Dimension::XYZ.size(), 3);
+    /// assert_eq!(Dimension::XYM.size(), 3);
     /// ```
     pub fn size(&self) -> usize {
         match self {
             Dimension::XY => 2,
-            Dimension::XYZ => 3,
+            Dimension::XYZ | Dimension::XYM => 3,
         }
     }
 }
@@ -75,10 +81,56 @@ impl From<Dimension> for geo_traits::Dimensions {
         match value {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 }
 
+/// The type of an individual geometry, independent of how it is stored in an array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeometryType {
+    /// A point.
+    Point,
+
+    /// A line string.
+    LineString,
+
+    /// A polygon.
+    Polygon,
+
+    /// A multi point.
+    MultiPoint,
+
+    /// A multi line string.
+    MultiLineString,
+
+    /// A multi polygon.
+    MultiPolygon,
+
+    /// A geometry collection.
+    GeometryCollection,
+
+    /// An axis-aligned rectangle.
+    Rect,
+}
+
+/// A [GeometryType] paired with the [Dimension] of its coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeometryTypeAndDimension {
+    /// The geometry type.
+    pub geometry_type: GeometryType,
+
+    /// The coordinate dimension.
+    pub dim: Dimension,
+}
+
+impl GeometryTypeAndDimension {
+    /// Construct a new [GeometryTypeAndDimension].
+    pub fn new(geometry_type: GeometryType, dim: Dimension) -> Self {
+        Self { geometry_type, dim }
+    }
+}
+
 /// A type enum representing "native" GeoArrow geometry types.
 ///
 /// This is designed to aid in downcasting from dynamically-typed geometry arrays.
@@ -170,6 +222,10 @@ pub(crate) fn coord_type_to_data_type(coord_type: CoordType, dim: Dimension) ->
             let values_field = Field::new("xyz", DataType::Float64, false);
             DataType::FixedSizeList(Arc::new(values_field), 3)
         }
+        (CoordType::Interleaved, Dimension::XYM) => {
+            let values_field = Field::new("xym", DataType::Float64, false);
+            DataType::FixedSizeList(Arc::new(values_field), 3)
+        }
         (CoordType::Separated, Dimension::XY) => {
             let values_fields = vec![
                 Field::new("x", DataType::Float64, false),
@@ -185,6 +241,14 @@ pub(crate) fn coord_type_to_data_type(coord_type: CoordType, dim: Dimension) ->
             ];
             DataType::Struct(values_fields.into())
         }
+        (CoordType::Separated, Dimension::XYM) => {
+            let values_fields = vec![
+                Field::new("x", DataType::Float64, false),
+                Field::new("y", DataType::Float64, false),
+                Field::new("m", DataType::Float64, false),
+            ];
+            DataType::Struct(values_fields.into())
+        }
     }
 }
 
@@ -233,6 +297,7 @@ fn mixed_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
     match dim {
         Dimension::XY => type_ids.extend([1, 2, 3, 4, 5, 6]),
         Dimension::XYZ => type_ids.extend([11, 12, 13, 14, 15, 16]),
+        Dimension::XYM => type_ids.extend([21, 22, 23, 24, 25, 26]),
     }
 
     // Note: we manually construct the fields because these fields shouldn't have their own
@@ -301,6 +366,16 @@ pub(crate) fn rect_fields(dim: Dimension) -> Fields {
                 Field::new("zmax", DataType::Float64, false),
             ]
         }
+        Dimension::XYM => {
+            vec![
+                Field::new("xmin", DataType::Float64, false),
+                Field::new("ymin", DataType::Float64, false),
+                Field::new("mmin", DataType::Float64, false),
+                Field::new("xmax", DataType::Float64, false),
+                Field::new("ymax", DataType::Float64, false),
+                Field::new("mmax", DataType::Float64, false),
+            ]
+        }
     };
 
     values_fields.into()
@@ -609,14 +684,28 @@ impl AnyType {
     }
 }
 
+/// Infer the dimension of a coordinate array from its size, using the name of the measure
+/// coordinate to distinguish XYM from XYZ.
+fn parse_dimension(size: usize, is_measured: bool) -> Result<Dimension> {
+    match Dimension::try_from(size)? {
+        Dimension::XYZ if is_measured => Ok(Dimension::XYM),
+        dim => Ok(dim),
+    }
+}
+
 fn parse_data_type(data_type: &DataType) -> Result<(CoordType, Dimension)> {
     match data_type {
-        DataType::FixedSizeList(_, list_size) => {
-            Ok((CoordType::Interleaved, (*list_size).try_into()?))
-        }
-        DataType::Struct(struct_fields) => {
-            Ok((CoordType::Separated, struct_fields.len().try_into()?))
-        }
+        DataType::FixedSizeList(inner_field, list_size) => Ok((
+            CoordType::Interleaved,
+            parse_dimension(*list_size as usize, inner_field.name() == "xym")?,
+        )),
+        DataType::Struct(struct_fields) => Ok((
+            CoordType::Separated,
+            parse_dimension(
+                struct_fields.len(),
+                struct_fields.iter().any(|field| field.name() == "m"),
+            )?,
+        )),
         dt => Err(GeoArrowError::General(format!("Unexpected data type {dt}"))),
     }
 }
@@ -821,6 +910,55 @@ fn parse_geometry(field: &Field) -> Result<NativeType> {
                         }
                         _ => unreachable!(),
                     },
+                    21 => match parse_point(field)? {
+                        NativeType::Point(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    22 => match parse_linestring(field)? {
+                        NativeType::LineString(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    23 => match parse_polygon(field)? {
+                        NativeType::Polygon(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    24 => match parse_multi_point(field)? {
+                        NativeType::MultiPoint(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    25 => match parse_multi_linestring(field)? {
+                        NativeType::MultiLineString(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    26 => match parse_multi_polygon(field)? {
+                        NativeType::MultiPolygon(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
+                    27 => match parse_geometry_collection(field)? {
+                        NativeType::GeometryCollection(ct, Dimension::XYM) => {
+                            coord_types.insert(ct);
+                            dimensions.insert(Dimension::XYM);
+                        }
+                        _ => unreachable!(),
+                    },
                     id => panic!("unexpected type id {}", id),
                 };
                 Ok::<_, GeoArrowError>(())
@@ -885,6 +1023,9 @@ fn parse_rect(field: &Field) -> NativeType {
     match field.data_type() {
         DataType::Struct(struct_fields) => match struct_fields.len() {
             4 => NativeType::Rect(Dimension::XY),
+            6 if struct_fields.iter().any(|field| field.name() == "mmin") => {
+                NativeType::Rect(Dimension::XYM)
+            }
             6 => NativeType::Rect(Dimension::XYZ),
             _ => panic!("unexpected number of struct fields"),
         },
@@ -915,12 +1056,7 @@ impl TryFrom<&Field> for NativeType {
             // metadata should use TryFrom for a specific geometry type directly, instead of using
             // GeometryArray
             let data_type = match field.data_type() {
-                DataType::Struct(struct_fields) => match struct_fields.len() {
-                    2 => NativeType::Point(CoordType::Separated, Dimension::XY),
-                    3 => NativeType::Point(CoordType::Separated, Dimension::XYZ),
-                    l => return Err(GeoArrowError::General(format!("incorrect number of struct fields {l}"))),
-                },
-                DataType::FixedSizeList(_, list_size) => NativeType::Point(CoordType::Interleaved, (*list_size as usize).try_into()?),
+                DataType::Struct(_) | DataType::FixedSizeList(_, _) => parse_point(field)?,
                 _ => return Err(GeoArrowError::General("Only FixedSizeList and Struct arrays are unambigously typed for a GeoArrow native type and can be used without extension metadata.".to_string())),
             };
             Ok(data_type)
@@ -1,5 +1,6 @@
 //! Defines [`GeoArrowError`], representing all errors returned by this crate.
 
+use crate::datatypes::Dimension;
 use arrow_schema::ArrowError;
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -21,6 +22,25 @@ pub enum GeoArrowError {
     #[error("General error: {0}")]
     General(String),
 
+    /// Arrays or types with different coordinate dimensions were passed to an operation that
+    /// requires them to match.
+    #[error("Dimension mismatch: expected {expected:?}, found {found:?}")]
+    DimensionMismatch {
+        /// The expected dimension.
+        expected: Dimension,
+        /// The dimension that was found instead.
+        found: Dimension,
+    },
+
+    /// Parsing the serialized geometry at `row` failed.
+    #[error("Failed to parse geometry at row {row}: {source}")]
+    ParseError {
+        /// The index of the geometry that failed to parse within its array.
+        row: usize,
+        /// The underlying parse error.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
     /// Whenever pushing to a container fails because it does not support more entries.
     ///
     /// The solution is usually to use a higher-capacity container-backing type.
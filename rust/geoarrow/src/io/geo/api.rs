@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use crate::algorithm::native::Downcast;
+use crate::array::*;
+use crate::datatypes::{Dimension, GeometryType, NativeType};
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Convert each element of `arr` into an owned [`geo::Geometry`].
+///
+/// Null elements are returned as `None`.
+pub fn to_geo_vec(arr: &dyn NativeArray) -> Vec<Option<geo::Geometry>> {
+    use NativeType::*;
+
+    macro_rules! impl_to_geo {
+        ($method:ident) => {
+            arr.$method()
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.map(geo::Geometry::from))
+                .collect()
+        };
+    }
+
+    match arr.data_type() {
+        Point(_, _) => impl_to_geo!(as_point),
+        LineString(_, _) => impl_to_geo!(as_line_string),
+        Polygon(_, _) => impl_to_geo!(as_polygon),
+        MultiPoint(_, _) => impl_to_geo!(as_multi_point),
+        MultiLineString(_, _) => impl_to_geo!(as_multi_line_string),
+        MultiPolygon(_, _) => impl_to_geo!(as_multi_polygon),
+        Mixed(_, _) => impl_to_geo!(as_mixed),
+        GeometryCollection(_, _) => impl_to_geo!(as_geometry_collection),
+        Rect(_) => impl_to_geo!(as_rect),
+    }
+}
+
+/// Build a GeoArrow array from owned [`geo::Geometry`] values.
+///
+/// The input is scanned to find the narrowest array type that can represent every geometry. If
+/// all non-null geometries are points or multi points, a [`PointArray`] or [`MultiPointArray`] is
+/// built, and likewise for line strings and polygons. Otherwise the geometries are collected into
+/// a [`GeometryCollectionArray`], which is downcast where possible. Null elements are preserved.
+///
+/// If `prefer_multi` is `true`, single geometries are always stored in their multi counterparts.
+pub fn from_geo_vec(
+    geoms: &[Option<geo::Geometry>],
+    coord_type: CoordType,
+    prefer_multi: bool,
+) -> Result<Arc<dyn NativeArray>> {
+    let dim = Dimension::XY;
+    let coord_type = Some(coord_type);
+
+    let result: Arc<dyn NativeArray> = match scan_geometry_types(geoms, prefer_multi) {
+        Some(GeometryType::Point) => Arc::new(
+            PointBuilder::from_nullable_geometries(geoms, dim, coord_type, Default::default())?
+                .finish(),
+        ),
+        Some(GeometryType::LineString) => Arc::new(
+            LineStringBuilder::from_nullable_geometries(
+                geoms,
+                dim,
+                coord_type,
+                Default::default(),
+            )?
+            .finish(),
+        ),
+        Some(GeometryType::Polygon) => Arc::new(
+            PolygonBuilder::from_nullable_geometries(geoms, dim, coord_type, Default::default())?
+                .finish(),
+        ),
+        Some(GeometryType::MultiPoint) => Arc::new(
+            MultiPointBuilder::from_nullable_geometries(
+                geoms,
+                dim,
+                coord_type,
+                Default::default(),
+            )?
+            .finish(),
+        ),
+        Some(GeometryType::MultiLineString) => Arc::new(
+            MultiLineStringBuilder::from_nullable_geometries(
+                geoms,
+                dim,
+                coord_type,
+                Default::default(),
+            )?
+            .finish(),
+        ),
+        Some(GeometryType::MultiPolygon) => Arc::new(
+            MultiPolygonBuilder::from_nullable_geometries(
+                geoms,
+                dim,
+                coord_type,
+                Default::default(),
+            )?
+            .finish(),
+        ),
+        _ => GeometryCollectionBuilder::from_nullable_geometries(
+            geoms,
+            dim,
+            coord_type,
+            Default::default(),
+            prefer_multi,
+        )?
+        .finish()
+        .downcast(true),
+    };
+    Ok(result)
+}
+
+/// Find the single geometry type whose array can hold every non-null geometry, if any.
+fn scan_geometry_types(
+    geoms: &[Option<geo::Geometry>],
+    prefer_multi: bool,
+) -> Option<GeometryType> {
+    let (mut points, mut multi_points) = (false, false);
+    let (mut line_strings, mut multi_line_strings) = (false, false);
+    let (mut polygons, mut multi_polygons) = (false, false);
+
+    for geom in geoms.iter().flatten() {
+        match geom {
+            geo::Geometry::Point(_) => points = true,
+            geo::Geometry::MultiPoint(_) => multi_points = true,
+            geo::Geometry::LineString(_) => line_strings = true,
+            geo::Geometry::MultiLineString(_) => multi_line_strings = true,
+            geo::Geometry::Polygon(_) => polygons = true,
+            geo::Geometry::MultiPolygon(_) => multi_polygons = true,
+            _ => return None,
+        }
+    }
+
+    let point_family = points || multi_points;
+    let line_string_family = line_strings || multi_line_strings;
+    let polygon_family = polygons || multi_polygons;
+
+    match (point_family, line_string_family, polygon_family) {
+        (true, false, false) if multi_points || prefer_multi => Some(GeometryType::MultiPoint),
+        (true, false, false) => Some(GeometryType::Point),
+        (false, true, false) if multi_line_strings || prefer_multi => {
+            Some(GeometryType::MultiLineString)
+        }
+        (false, true, false) => Some(GeometryType::LineString),
+        (false, false, true) if multi_polygons || prefer_multi => Some(GeometryType::MultiPolygon),
+        (false, false, true) => Some(GeometryType::Polygon),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+
+    #[test]
+    fn point_array_to_geo_vec() {
+        let arr = point::point_array();
+        let geoms = to_geo_vec(&arr);
+        let expected = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            Some(geo::Geometry::Point(point::p1())),
+            Some(geo::Geometry::Point(point::p2())),
+        ];
+        assert_eq!(geoms, expected);
+    }
+
+    #[test]
+    fn polygons_from_geo_vec() {
+        let geoms = vec![
+            Some(geo::Geometry::Polygon(polygon::p0())),
+            None,
+            Some(geo::Geometry::Polygon(polygon::p1())),
+        ];
+        let arr = from_geo_vec(&geoms, CoordType::Interleaved, false).unwrap();
+        assert_eq!(
+            arr.data_type(),
+            NativeType::Polygon(CoordType::Interleaved, Dimension::XY)
+        );
+        assert_eq!(to_geo_vec(arr.as_ref()), geoms);
+
+        let arr = from_geo_vec(&geoms, CoordType::Interleaved, true).unwrap();
+        assert_eq!(
+            arr.data_type(),
+            NativeType::MultiPolygon(CoordType::Interleaved, Dimension::XY)
+        );
+    }
+}
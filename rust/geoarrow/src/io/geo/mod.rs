@@ -0,0 +1,5 @@
+//! Export to and import from owned geometries of the [`geo`] crate.
+
+mod api;
+
+pub use api::{from_geo_vec, to_geo_vec};
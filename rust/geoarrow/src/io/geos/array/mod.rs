@@ -5,5 +5,9 @@ mod mixed;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod native;
 mod point;
 mod polygon;
+
+pub(crate) use native::as_geos_accessor;
+pub use native::to_geos_vec;
@@ -0,0 +1,58 @@
+use crate::array::AsNativeArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Downcast a [`NativeArray`] to a [`NativeGEOSGeometryAccessor`] over its concrete array type.
+pub(crate) fn as_geos_accessor<'a>(
+    array: &'a dyn NativeArray,
+) -> Result<&'a dyn NativeGEOSGeometryAccessor<'a>> {
+    use NativeType::*;
+
+    let accessor: &'a dyn NativeGEOSGeometryAccessor<'a> = match array.data_type() {
+        Point(_, _) => array.as_point(),
+        LineString(_, _) => array.as_line_string(),
+        Polygon(_, _) => array.as_polygon(),
+        MultiPoint(_, _) => array.as_multi_point(),
+        MultiLineString(_, _) => array.as_multi_line_string(),
+        MultiPolygon(_, _) => array.as_multi_polygon(),
+        Mixed(_, _) => array.as_mixed(),
+        GeometryCollection(_, _) => array.as_geometry_collection(),
+        Rect(_) => {
+            return Err(GeoArrowError::IncorrectType(
+                "Rect arrays are not supported with GEOS".into(),
+            ))
+        }
+    };
+    Ok(accessor)
+}
+
+/// Convert every geometry of `arr` to a GEOS geometry, with `None` for null rows.
+///
+/// This is useful for building GEOS structures over a whole array, such as an `STRtree` or
+/// prepared geometries.
+pub fn to_geos_vec(arr: &dyn NativeArray) -> Result<Vec<Option<geos::Geometry>>> {
+    let accessor = as_geos_accessor(arr)?;
+    let geoms = (0..arr.len())
+        .map(|i| accessor.get_as_geometry(i))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(geoms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use geos::{Geom, GeometryTypes};
+
+    #[test]
+    fn polygon_array() {
+        let arr = p_array();
+        let geoms = to_geos_vec(&arr).unwrap();
+        assert_eq!(geoms.len(), arr.len());
+        for geom in geoms {
+            assert_eq!(geom.unwrap().geometry_type(), GeometryTypes::Polygon);
+        }
+    }
+}
@@ -2,3 +2,6 @@
 
 mod array;
 pub mod scalar;
+
+pub(crate) use array::as_geos_accessor;
+pub use array::to_geos_vec;
@@ -16,6 +16,12 @@ impl TryFrom<SeparatedCoordBuffer> for CoordSeq {
                 Some(&value.buffers[2]),
                 None,
             ),
+            Dimension::XYM => CoordSeq::new_from_arrays(
+                &value.buffers[0],
+                &value.buffers[1],
+                None,
+                Some(&value.buffers[2]),
+            ),
         }
     }
 }
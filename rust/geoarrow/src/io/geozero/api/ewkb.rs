@@ -6,15 +6,19 @@ use crate::array::geometrycollection::GeometryCollectionBuilder;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::chunked_array::{
-    ChunkedGeometryCollectionArray, ChunkedMixedGeometryArray, ChunkedNativeArray, ChunkedWKBArray,
+    ChunkedGeometryArray, ChunkedGeometryCollectionArray, ChunkedMixedGeometryArray,
+    ChunkedNativeArray, ChunkedWKBArray,
 };
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
-use crate::NativeArray;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+use arrow_array::builder::GenericBinaryBuilder;
 use arrow_array::{Array, OffsetSizeTrait};
 use geozero::geo_types::GeoWriter;
 use geozero::wkb::process_ewkb_geom;
+use geozero::{CoordDimensions, GeozeroGeometry, ToWkb};
 
 pub trait FromEWKB: Sized {
     type Input<O: OffsetSizeTrait>;
@@ -153,3 +157,113 @@ impl FromEWKB for Arc<dyn ChunkedNativeArray> {
         Ok(geom_arr.downcast(true))
     }
 }
+
+fn coord_dimensions(dim: Dimension) -> CoordDimensions {
+    match dim {
+        Dimension::XY => CoordDimensions::xy(),
+        Dimension::XYZ => CoordDimensions::xyz(),
+        Dimension::XYM => CoordDimensions::xym(),
+    }
+}
+
+fn array_to_ewkb<'a, O: OffsetSizeTrait, A: ArrayAccessor<'a>>(
+    arr: &'a A,
+    dim: Dimension,
+    srid: Option<i32>,
+) -> Result<WKBArray<O>>
+where
+    A::Item: GeozeroGeometry,
+{
+    let dims = coord_dimensions(dim);
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(arr.len(), 0);
+    for maybe_geom in arr.iter() {
+        match maybe_geom {
+            Some(geom) => builder.append_value(geom.to_ewkb(dims, srid)?),
+            None => builder.append_null(),
+        }
+    }
+    Ok(WKBArray::new(builder.finish(), arr.metadata()))
+}
+
+/// Convert a geometry array to an EWKB-encoded [WKBArray].
+///
+/// If `srid` is provided, it is embedded in every non-null geometry.
+pub fn to_ewkb<O: OffsetSizeTrait>(
+    arr: &dyn NativeArray,
+    srid: Option<i32>,
+) -> Result<WKBArray<O>> {
+    use NativeType::*;
+
+    let dim = arr.dimension();
+    match arr.data_type() {
+        Point(_, _) => array_to_ewkb(arr.as_point(), dim, srid),
+        LineString(_, _) => array_to_ewkb(arr.as_line_string(), dim, srid),
+        Polygon(_, _) => array_to_ewkb(arr.as_polygon(), dim, srid),
+        MultiPoint(_, _) => array_to_ewkb(arr.as_multi_point(), dim, srid),
+        MultiLineString(_, _) => array_to_ewkb(arr.as_multi_line_string(), dim, srid),
+        MultiPolygon(_, _) => array_to_ewkb(arr.as_multi_polygon(), dim, srid),
+        Mixed(_, _) => array_to_ewkb(arr.as_mixed(), dim, srid),
+        GeometryCollection(_, _) => array_to_ewkb(arr.as_geometry_collection(), dim, srid),
+        Rect(_) => Err(GeoArrowError::IncorrectType(
+            "Rect arrays cannot be written to EWKB".into(),
+        )),
+    }
+}
+
+/// Convert a chunked geometry array to a chunked EWKB-encoded array.
+///
+/// Each chunk is converted independently, so the output can be consumed chunk by chunk, e.g. when
+/// streaming into a database.
+pub fn chunked_to_ewkb<O: OffsetSizeTrait>(
+    arr: &dyn ChunkedNativeArray,
+    srid: Option<i32>,
+) -> Result<ChunkedWKBArray<O>> {
+    let chunks = arr
+        .geometry_chunks()
+        .iter()
+        .map(|chunk| to_ewkb(chunk.as_ref(), srid))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ChunkedGeometryArray::new(chunks))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn ewkb_round_trip() {
+        let arr = point::point_array();
+        let ewkb_arr: WKBArray<i32> = to_ewkb(&arr, Some(4326)).unwrap();
+        let roundtrip = MixedGeometryArray::from_ewkb(
+            &ewkb_arr,
+            CoordType::Interleaved,
+            Dimension::XY,
+            Default::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(roundtrip.len(), arr.len());
+    }
+
+    #[test]
+    fn chunked_ewkb_matches_single_array() {
+        let arr = point::point_array();
+        let chunked = ChunkedGeometryArray::new(vec![arr.slice(0, 1), arr.slice(1, 2)]);
+
+        let expected: WKBArray<i32> = to_ewkb(&arr, Some(4326)).unwrap();
+        let chunked_output: ChunkedWKBArray<i32> = chunked_to_ewkb(&chunked, Some(4326)).unwrap();
+        assert_eq!(chunked_output.chunks().len(), 2);
+
+        let actual = chunked_output
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.iter().map(|wkb| wkb.unwrap().as_slice().to_vec()))
+            .collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .map(|wkb| wkb.unwrap().as_slice().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+}
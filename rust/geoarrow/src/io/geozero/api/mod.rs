@@ -1,5 +1,5 @@
 mod ewkb;
 mod wkt;
 
-pub use ewkb::FromEWKB;
-pub use wkt::FromWKT;
+pub use ewkb::{chunked_to_ewkb, to_ewkb, FromEWKB};
+pub use wkt::{read_wkt_lines, FromWKT};
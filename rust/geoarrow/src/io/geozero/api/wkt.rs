@@ -1,3 +1,4 @@
+use std::io::BufRead;
 use std::sync::Arc;
 
 use crate::algorithm::native::Downcast;
@@ -5,10 +6,11 @@ use crate::array::geometrycollection::GeometryCollectionBuilder;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::chunked_array::{
-    ChunkedArray, ChunkedGeometryCollectionArray, ChunkedMixedGeometryArray, ChunkedNativeArray,
+    ChunkedArray, ChunkedGeometryArray, ChunkedGeometryCollectionArray, ChunkedMixedGeometryArray,
+    ChunkedNativeArray,
 };
 use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::NativeArray;
 use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
@@ -26,6 +28,13 @@ pub trait FromWKT: Sized {
     ) -> Result<Self>;
 }
 
+fn wkt_parse_error(row: usize, err: geozero::error::GeozeroError) -> GeoArrowError {
+    GeoArrowError::ParseError {
+        row,
+        source: Box::new(err),
+    }
+}
+
 impl FromWKT for MixedGeometryArray {
     type Input<O: OffsetSizeTrait> = GenericStringArray<O>;
 
@@ -41,7 +50,9 @@ impl FromWKT for MixedGeometryArray {
         for i in 0..arr.len() {
             if arr.is_valid(i) {
                 let wkt_str = geozero::wkt::Wkt(arr.value(i));
-                wkt_str.process_geom(&mut builder)?;
+                wkt_str
+                    .process_geom(&mut builder)
+                    .map_err(|err| wkt_parse_error(i, err))?;
             } else {
                 builder.push_null();
             }
@@ -62,16 +73,27 @@ impl FromWKT for GeometryCollectionArray {
         prefer_multi: bool,
     ) -> Result<Self> {
         // TODO: Add GeometryCollectionStreamBuilder and use that instead of going through geo
+        let geo_geoms = (0..arr.len())
+            .map(|i| {
+                if arr.is_valid(i) {
+                    let wkt_str = geozero::wkt::Wkt(arr.value(i));
+                    Ok(Some(
+                        wkt_str.to_geo().map_err(|err| wkt_parse_error(i, err))?,
+                    ))
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // All geometries are parsed up front, so we can size the builder once instead of growing
+        // it on every push.
+        let capacity = GeometryCapacity::from_geometries(geo_geoms.iter().map(|g| g.as_ref()))?;
         let mut builder =
             GeometryCollectionBuilder::new_with_options(dim, coord_type, metadata, prefer_multi);
-        for i in 0..arr.len() {
-            if arr.is_valid(i) {
-                let wkt_str = geozero::wkt::Wkt(arr.value(i));
-                let geo_geom = wkt_str.to_geo()?;
-                builder.push_geometry(Some(&geo_geom))?;
-            } else {
-                builder.push_null();
-            }
+        builder.reserve_exact(capacity);
+        for geo_geom in geo_geoms.iter() {
+            builder.push_geometry(geo_geom.as_ref())?;
         }
 
         Ok(builder.finish())
@@ -144,10 +166,71 @@ impl FromWKT for Arc<dyn ChunkedNativeArray> {
     }
 }
 
+/// The number of geometries in each chunk output by [`read_wkt_lines`].
+const WKT_LINES_CHUNK_SIZE: usize = 65_536;
+
+/// Parse newline-delimited WKT, with one geometry per line, into a chunked array.
+///
+/// Lines are parsed as they are read, so the input never needs to be held in memory as a whole.
+/// Each output chunk holds up to 65,536 geometries. Blank lines are read as null geometries.
+pub fn read_wkt_lines(
+    reader: impl BufRead,
+    coord_type: CoordType,
+    dim: Dimension,
+    prefer_multi: bool,
+) -> Result<Arc<dyn ChunkedNativeArray>> {
+    read_wkt_lines_with_chunk_size(reader, coord_type, dim, prefer_multi, WKT_LINES_CHUNK_SIZE)
+}
+
+fn read_wkt_lines_with_chunk_size(
+    reader: impl BufRead,
+    coord_type: CoordType,
+    dim: Dimension,
+    prefer_multi: bool,
+    chunk_size: usize,
+) -> Result<Arc<dyn ChunkedNativeArray>> {
+    let new_builder = || {
+        MixedGeometryStreamBuilder::new_with_options(
+            dim,
+            coord_type,
+            Default::default(),
+            prefer_multi,
+        )
+    };
+
+    let mut chunks = vec![];
+    let mut builder = new_builder();
+    let mut chunk_len = 0;
+    for (row, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            builder.push_null();
+        } else {
+            geozero::wkt::Wkt(line)
+                .process_geom(&mut builder)
+                .map_err(|err| wkt_parse_error(row, err))?;
+        }
+
+        chunk_len += 1;
+        if chunk_len == chunk_size {
+            chunks.push(std::mem::replace(&mut builder, new_builder()).finish());
+            chunk_len = 0;
+        }
+    }
+
+    if chunk_len > 0 || chunks.is_empty() {
+        chunks.push(builder.finish());
+    }
+
+    Ok(Arc::new(ChunkedGeometryArray::new(chunks)))
+}
+
 #[cfg(test)]
 mod test {
     use crate::datatypes::{Dimension, NativeType};
     use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
     use arrow_array::builder::StringBuilder;
 
     use super::*;
@@ -197,4 +280,55 @@ mod test {
             NativeType::Point(_, Dimension::XY)
         ));
     }
+
+    #[test]
+    fn test_read_wkt_lines() {
+        let input = "POINT (30 10)\n\nLINESTRING (30 10, 10 30, 40 40)\r\nPOINT (1 2)\n";
+        let chunked = read_wkt_lines_with_chunk_size(
+            input.as_bytes(),
+            Default::default(),
+            Dimension::XY,
+            false,
+            2,
+        )
+        .unwrap();
+        assert_eq!(chunked.len(), 4);
+        assert_eq!(chunked.num_chunks(), 2);
+
+        let chunks = chunked.as_ref().as_mixed().chunks();
+        assert!(chunks[0].is_valid(0));
+        assert!(chunks[0].is_null(1));
+        let line_string = geo::LineString::try_from(chunks[1].value_as_geo(0)).unwrap();
+        assert_eq!(line_string.0.len(), 3);
+        let point = geo::Point::try_from(chunks[1].value_as_geo(1)).unwrap();
+        assert_eq!(point, geo::point!(x: 1., y: 2.));
+    }
+
+    #[test]
+    fn test_read_wkt_lines_single_chunk() {
+        let input = "POINT (30 10)\nPOINT (20 5)\nPOINT (3 10)";
+        let chunked =
+            read_wkt_lines(input.as_bytes(), Default::default(), Dimension::XY, false).unwrap();
+        assert_eq!(chunked.len(), 3);
+        assert_eq!(chunked.num_chunks(), 1);
+    }
+
+    #[test]
+    fn wkt_parse_error_row() {
+        let mut builder = StringBuilder::new();
+        builder.append_value("POINT (30 10)");
+        builder.append_null();
+        builder.append_value("POINT (30");
+        let arr = builder.finish();
+
+        let err = MixedGeometryArray::from_wkt(
+            &arr,
+            Default::default(),
+            Dimension::XY,
+            Default::default(),
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, GeoArrowError::ParseError { row: 2, .. }));
+    }
 }
@@ -6,7 +6,7 @@ pub(crate) mod array;
 mod scalar;
 pub(crate) mod table;
 
-pub use api::{FromEWKB, FromWKT};
+pub use api::{chunked_to_ewkb, read_wkt_lines, to_ewkb, FromEWKB, FromWKT};
 pub use array::{
     ToLineStringArray, ToMixedArray, ToMultiLineStringArray, ToMultiPointArray,
     ToMultiPolygonArray, ToPointArray, ToPolygonArray,
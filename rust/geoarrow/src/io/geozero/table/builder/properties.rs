@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow_array::RecordBatch;
+use arrow_array::{RecordBatch, RecordBatchOptions};
 use arrow_schema::{Field, Schema, SchemaBuilder};
 use chrono::{DateTime, Utc};
 use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
@@ -117,6 +117,8 @@ impl PropertiesBatchBuilder {
     }
 
     pub fn finish(self) -> Result<RecordBatch> {
+        // Set the row count explicitly so that a batch without any properties keeps its length
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_counter));
         let mut schema_builder = SchemaBuilder::with_capacity(self.columns.len());
         let mut columns = Vec::with_capacity(self.columns.len());
 
@@ -126,9 +128,10 @@ impl PropertiesBatchBuilder {
             columns.push(array);
         }
 
-        Ok(RecordBatch::try_new(
+        Ok(RecordBatch::try_new_with_options(
             Arc::new(schema_builder.finish()),
             columns,
+            &options,
         )?)
     }
 }
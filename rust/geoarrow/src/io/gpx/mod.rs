@@ -0,0 +1,5 @@
+//! Read from [GPX](https://www.topografix.com/gpx.asp) files.
+
+pub use reader::from_gpx;
+
+mod reader;
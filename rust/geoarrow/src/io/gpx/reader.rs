@@ -0,0 +1,136 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use arrow_array::builder::{ListBuilder, StringBuilder, TimestampMicrosecondBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{Field, Schema};
+use time::OffsetDateTime;
+
+use crate::algorithm::native::rebuild::SimpleCoord;
+use crate::array::LineStringBuilder;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::table::Table;
+
+/// Read the tracks of a GPX file into a Table.
+///
+/// Each track segment becomes one line string row. If any track point has an elevation, the
+/// geometry column is three-dimensional, and points without an elevation get a Z value of NaN.
+///
+/// Besides the geometry, the table has two columns:
+///
+/// - `track`: the name of the track the segment belongs to.
+/// - `time`: a list with the timestamp of each point in the segment, in UTC.
+///
+/// Waypoints and routes are not read.
+pub fn from_gpx(reader: impl Read) -> Result<Table> {
+    let gpx = gpx::read(reader).map_err(|err| GeoArrowError::General(err.to_string()))?;
+
+    let segments = gpx
+        .tracks
+        .iter()
+        .flat_map(|track| {
+            track
+                .segments
+                .iter()
+                .map(move |segment| (track.name.as_deref(), segment))
+        })
+        .collect::<Vec<_>>();
+    let has_z = segments
+        .iter()
+        .flat_map(|(_, segment)| segment.points.iter())
+        .any(|point| point.elevation.is_some());
+    let dim = if has_z { Dimension::XYZ } else { Dimension::XY };
+
+    let mut geometry_builder = LineStringBuilder::new(dim);
+    let mut track_builder = StringBuilder::with_capacity(segments.len(), 0);
+    let mut time_builder =
+        ListBuilder::new(TimestampMicrosecondBuilder::new().with_timezone("UTC"));
+    for (track_name, segment) in segments.iter() {
+        for point in segment.points.iter() {
+            let coord = SimpleCoord {
+                x: point.point().x(),
+                y: point.point().y(),
+                z: has_z.then(|| point.elevation.unwrap_or(f64::NAN)),
+                dim: dim.into(),
+            };
+            unsafe { geometry_builder.push_coord(&coord)? };
+
+            let timestamp = point
+                .time
+                .map(|time| (OffsetDateTime::from(time).unix_timestamp_nanos() / 1_000) as i64);
+            time_builder.values().append_option(timestamp);
+        }
+        geometry_builder.try_push_length(segment.points.len())?;
+        time_builder.append(true);
+        track_builder.append_option(*track_name);
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(track_builder.finish()),
+        Arc::new(time_builder.finish()),
+    ];
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("track", columns[0].data_type().clone(), true),
+        Field::new("time", columns[1].data_type().clone(), true),
+    ]));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    let geometry = ChunkedGeometryArray::new(vec![geometry_builder.finish()]);
+    Table::from_arrow_and_geometry(vec![batch], schema, Arc::new(geometry))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsChunkedNativeArray;
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::TimestampMicrosecondType;
+    use geo_traits::{CoordTrait, LineStringTrait};
+
+    #[test]
+    fn two_track_points() {
+        let gpx = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk>
+    <name>Morning run</name>
+    <trkseg>
+      <trkpt lat="47.6" lon="-122.3">
+        <ele>10.5</ele>
+        <time>2024-01-01T08:00:00Z</time>
+      </trkpt>
+      <trkpt lat="47.7" lon="-122.4">
+        <ele>12.0</ele>
+        <time>2024-01-01T08:00:10Z</time>
+      </trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+        let table = from_gpx(gpx.as_bytes()).unwrap();
+        assert_eq!(table.len(), 1);
+
+        let geometry = table.geometry().unwrap();
+        let line_strings = &geometry.as_ref().as_line_string().chunks()[0];
+        assert_eq!(line_strings.dimension(), Dimension::XYZ);
+        let line_string = line_strings.value(0);
+        assert_eq!(line_string.num_coords(), 2);
+        let first = line_string.coord(0).unwrap();
+        assert_eq!(
+            (first.x(), first.y(), first.nth_or_panic(2)),
+            (-122.3, 47.6, 10.5)
+        );
+
+        let batch = &table.batches()[0];
+        let track = batch.column_by_name("track").unwrap().as_string::<i32>();
+        assert_eq!(track.value(0), "Morning run");
+        let times = batch
+            .column_by_name("time")
+            .unwrap()
+            .as_list::<i32>()
+            .value(0);
+        let times = times.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(times.len(), 2);
+        assert_eq!(times.value(1) - times.value(0), 10_000_000);
+    }
+}
@@ -36,3 +36,34 @@ pub fn write_ipc_stream<W: Write, S: Into<RecordBatchReader>>(stream: S, writer:
     writer.finish()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::io::ipc::{read_ipc, read_ipc_stream};
+    use crate::test::point;
+
+    #[test]
+    fn ipc_round_trip() {
+        let table = point::table();
+        let mut buf = vec![];
+        write_ipc(&table, &mut buf).unwrap();
+        let round_trip = read_ipc(Cursor::new(buf)).unwrap();
+        assert_eq!(round_trip, table);
+        assert_eq!(
+            round_trip.geometry_type().unwrap(),
+            table.geometry_type().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipc_stream_round_trip() {
+        let table = point::table();
+        let mut buf = vec![];
+        write_ipc_stream(&table, &mut buf).unwrap();
+        let round_trip = read_ipc_stream(buf.as_slice()).unwrap();
+        assert_eq!(round_trip, table);
+    }
+}
@@ -10,18 +10,24 @@ pub mod display;
 pub mod flatgeobuf;
 #[cfg(feature = "gdal")]
 pub mod gdal;
+pub mod geo;
 pub mod geojson;
 pub mod geojson_lines;
 #[cfg(feature = "geos")]
 pub mod geos;
 pub mod geozero;
+#[cfg(feature = "gpx")]
+pub mod gpx;
 pub mod ipc;
+#[cfg(feature = "mvt")]
+pub mod mvt;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "postgis")]
 pub mod postgis;
 pub mod shapefile;
 mod stream;
+pub mod topojson;
 pub mod wkb;
 pub mod wkt;
 
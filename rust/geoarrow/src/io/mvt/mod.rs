@@ -0,0 +1,9 @@
+//! Read from and write to Mapbox Vector Tiles (MVT).
+
+pub use reader::from_mvt;
+pub use tile::TileCoord;
+pub use writer::to_mvt;
+
+mod reader;
+mod tile;
+mod writer;
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, StringArray};
+use arrow_schema::{DataType, Field};
+use geo::AffineTransform;
+use geozero::mvt::{Message, Tile};
+
+use crate::algorithm::geo::AffineOps;
+use crate::array::CoordType;
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geozero::array::MixedGeometryStreamBuilder;
+use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::mvt::TileCoord;
+use crate::table::Table;
+
+/// Decode a Mapbox Vector Tile into a [`Table`].
+///
+/// The features of all layers are read into a single table, whose columns are the union of the
+/// properties of every layer. A trailing `layer` column records the name of the layer each
+/// feature was decoded from. Geometries are scaled from integer tile coordinates in
+/// `[0, extent]` to Web Mercator (EPSG:3857) within the bounds of `tile`, the inverse of
+/// [`to_mvt`][super::to_mvt].
+pub fn from_mvt(bytes: &[u8], tile: TileCoord, extent: u32) -> Result<Table> {
+    let tile_data = Tile::decode(bytes).map_err(|err| GeoArrowError::General(err.to_string()))?;
+
+    let options = GeoTableBuilderOptions::new(
+        CoordType::Interleaved,
+        true,
+        None,
+        None,
+        None,
+        Default::default(),
+    );
+    let mut geo_table =
+        GeoTableBuilder::<MixedGeometryStreamBuilder>::new_with_options(Dimension::XY, options);
+    let mut layer_names = vec![];
+    for layer in tile_data.layers.iter() {
+        geozero::mvt::process(layer, &mut geo_table)?;
+        layer_names.extend(std::iter::repeat(layer.name.as_str()).take(layer.features.len()));
+    }
+    let mut table = geo_table.finish()?;
+
+    if layer_names.len() != table.len() {
+        return Err(GeoArrowError::General(format!(
+            "Expected {} features in tile, decoded {}",
+            layer_names.len(),
+            table.len()
+        )));
+    }
+    let layer_array = StringArray::from(layer_names);
+    let mut offset = 0;
+    let layer_chunks = table
+        .batches()
+        .iter()
+        .map(|batch| {
+            let chunk = layer_array.slice(offset, batch.num_rows());
+            offset += batch.num_rows();
+            Arc::new(chunk) as Arc<dyn Array>
+        })
+        .collect();
+    table.append_column(
+        Field::new("layer", DataType::Utf8, false).into(),
+        layer_chunks,
+    )?;
+
+    // Tile coordinates have their origin at the top left, with y increasing downwards
+    let (left, bottom, right, top) = tile.bounds();
+    let transform = AffineTransform::new(
+        (right - left) / extent as f64,
+        0.,
+        left,
+        0.,
+        -(top - bottom) / extent as f64,
+        top,
+    );
+    let geometry = table.geometry()?.as_ref().affine_transform(&transform)?;
+    table.with_geometry(geometry)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+
+    use crate::array::{AsChunkedNativeArray, PolygonArray};
+    use crate::io::mvt::to_mvt;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn round_trip() {
+        let tile = TileCoord::new(0, 0, 0);
+        let (left, _, right, _) = tile.bounds();
+        let half = (right - left) / 2.;
+        let polygon = geo::polygon![
+            (x: -half / 2., y: 0.),
+            (x: 0., y: 0.),
+            (x: 0., y: half / 2.),
+            (x: -half / 2., y: half / 2.),
+        ];
+        let arr: PolygonArray = (vec![polygon.clone()].as_slice(), Dimension::XY).into();
+        let bytes = to_mvt(&arr, "polygons", tile, 4096).unwrap();
+
+        let table = from_mvt(&bytes, tile, 4096).unwrap();
+        assert_eq!(table.len(), 1);
+        let geometry = table.geometry().unwrap();
+        let decoded = geometry.as_ref().as_mixed().chunks()[0].value_as_geo(0);
+        let decoded = match decoded {
+            geo::Geometry::Polygon(polygon) => polygon,
+            geo::Geometry::MultiPolygon(mut multi_polygon) if multi_polygon.0.len() == 1 => {
+                multi_polygon.0.remove(0)
+            }
+            other => panic!("unexpected geometry {other:?}"),
+        };
+        assert_eq!(decoded, polygon);
+    }
+
+    #[test]
+    fn records_layer_name() {
+        let tile = TileCoord::new(0, 0, 0);
+        let polygon = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 1000., y: 0.),
+            (x: 1000., y: 1000.),
+            (x: 0., y: 1000.),
+        ];
+        let arr: PolygonArray = (vec![polygon.clone(), polygon].as_slice(), Dimension::XY).into();
+
+        // Repeated protobuf fields are merged on decode, so concatenated tiles hold both layers
+        let mut bytes = to_mvt(&arr, "first", tile, 4096).unwrap();
+        bytes.extend(to_mvt(&arr.slice(0, 1), "second", tile, 4096).unwrap());
+
+        let table = from_mvt(&bytes, tile, 4096).unwrap();
+        assert_eq!(table.len(), 3);
+        let layer_idx = table.schema().index_of("layer").unwrap();
+        let layers = table.batches()[0].column(layer_idx).as_string::<i32>();
+        assert_eq!(
+            layers.iter().collect::<Vec<_>>(),
+            vec![Some("first"), Some("first"), Some("second")]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+/// Half the circumference of the Earth in Web Mercator (EPSG:3857) meters.
+const WEB_MERCATOR_HALF_EXTENT: f64 = 20_037_508.342_789_244;
+
+/// The address of a tile in the XYZ tiling scheme over Web Mercator (EPSG:3857).
+///
+/// `x` increases eastwards and `y` increases southwards, so tile `(0, 0)` is the north-west tile
+/// at every zoom level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TileCoord {
+    pub fn new(z: u8, x: u32, y: u32) -> Self {
+        Self { z, x, y }
+    }
+
+    /// The bounds of this tile in Web Mercator meters, as `(left, bottom, right, top)`.
+    pub fn bounds(&self) -> (f64, f64, f64, f64) {
+        let tile_size = 2. * WEB_MERCATOR_HALF_EXTENT / 2_f64.powi(self.z as i32);
+        let left = -WEB_MERCATOR_HALF_EXTENT + self.x as f64 * tile_size;
+        let top = WEB_MERCATOR_HALF_EXTENT - self.y as f64 * tile_size;
+        (left, top - tile_size, left + tile_size, top)
+    }
+}
@@ -0,0 +1,103 @@
+use geozero::mvt::{tile, Message, Tile};
+use geozero::ToMvt;
+
+use crate::array::AsNativeArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::TileCoord;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Encode the geometries of `arr` as a single-layer Mapbox Vector Tile.
+///
+/// The geometries must be in Web Mercator (EPSG:3857). They are scaled from the bounds of `tile`
+/// into integer tile coordinates in `[0, extent]`, with the origin at the top left. Each non-null
+/// geometry becomes one feature whose id is its row index; null geometries are skipped.
+///
+/// Geometries are not clipped to the tile.
+pub fn to_mvt(
+    arr: &dyn NativeArray,
+    layer_name: &str,
+    tile: TileCoord,
+    extent: u32,
+) -> Result<Vec<u8>> {
+    let (left, bottom, right, top) = tile.bounds();
+    let mut features = vec![];
+
+    macro_rules! impl_features {
+        ($cast_func:ident) => {
+            for (i, maybe_geom) in arr.$cast_func().iter().enumerate() {
+                if let Some(geom) = maybe_geom {
+                    let mut feature = geom.to_mvt(extent, left, bottom, right, top)?;
+                    feature.id = Some(i as u64);
+                    features.push(feature);
+                }
+            }
+        };
+    }
+
+    use NativeType::*;
+
+    match arr.data_type() {
+        Point(_, _) => impl_features!(as_point),
+        LineString(_, _) => impl_features!(as_line_string),
+        Polygon(_, _) => impl_features!(as_polygon),
+        MultiPoint(_, _) => impl_features!(as_multi_point),
+        MultiLineString(_, _) => impl_features!(as_multi_line_string),
+        MultiPolygon(_, _) => impl_features!(as_multi_polygon),
+        Mixed(_, _) => impl_features!(as_mixed),
+        GeometryCollection(_, _) => impl_features!(as_geometry_collection),
+        Rect(_) => return Err(GeoArrowError::IncorrectType("".into())),
+    };
+
+    let layer = tile::Layer {
+        version: 2,
+        name: layer_name.to_string(),
+        features,
+        keys: vec![],
+        values: vec![],
+        extent: Some(extent),
+    };
+    let tile = Tile {
+        layers: vec![layer],
+    };
+    Ok(tile.encode_to_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use geo::BoundingRect;
+    use geozero::ToGeo;
+
+    #[test]
+    fn polygon_round_trip() {
+        let (left, _, right, _) = TileCoord::new(0, 0, 0).bounds();
+        let half = (right - left) / 2.;
+        let polygon = geo::polygon![
+            (x: -half / 2., y: 0.),
+            (x: 0., y: 0.),
+            (x: 0., y: half / 2.),
+            (x: -half / 2., y: half / 2.),
+        ];
+        let arr: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+
+        let bytes = to_mvt(&arr, "polygons", TileCoord::new(0, 0, 0), 4096).unwrap();
+        assert!(!bytes.is_empty());
+
+        let tile = Tile::decode(bytes.as_slice()).unwrap();
+        assert_eq!(tile.layers.len(), 1);
+        let layer = &tile.layers[0];
+        assert_eq!(layer.name, "polygons");
+        assert_eq!(layer.extent, Some(4096));
+        assert_eq!(layer.features.len(), 1);
+
+        let feature = &layer.features[0];
+        assert_eq!(feature.r#type(), tile::GeomType::Polygon);
+        let bounds = feature.to_geo().unwrap().bounding_rect().unwrap();
+        assert_eq!(bounds.min(), geo::coord! { x: 1024., y: 1024. });
+        assert_eq!(bounds.max(), geo::coord! { x: 2048., y: 2048. });
+    }
+}
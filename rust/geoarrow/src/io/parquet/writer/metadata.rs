@@ -214,42 +214,45 @@ impl GeoParquetMetadataBuilder {
     }
 }
 
+/// GeoParquet has no geometry types with a measure, so XYM geometries are described by their 2D
+/// geometry type.
 pub fn get_geometry_types(data_type: &NativeType) -> HashSet<GeoParquetGeometryType> {
     use GeoParquetGeometryType::*;
     let mut geometry_types = HashSet::new();
 
     match data_type {
-        NativeType::Point(_, Dimension::XY) => {
+        NativeType::Point(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(Point);
         }
         NativeType::Point(_, Dimension::XYZ) => {
             geometry_types.insert(PointZ);
         }
-        NativeType::LineString(_, Dimension::XY) => {
+        NativeType::LineString(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(LineString);
         }
         NativeType::LineString(_, Dimension::XYZ) => {
             geometry_types.insert(LineStringZ);
         }
-        NativeType::Polygon(_, Dimension::XY) | NativeType::Rect(Dimension::XY) => {
+        NativeType::Polygon(_, Dimension::XY | Dimension::XYM)
+        | NativeType::Rect(Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(Polygon);
         }
         NativeType::Polygon(_, Dimension::XYZ) | NativeType::Rect(Dimension::XYZ) => {
             geometry_types.insert(PolygonZ);
         }
-        NativeType::MultiPoint(_, Dimension::XY) => {
+        NativeType::MultiPoint(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiPoint);
         }
         NativeType::MultiPoint(_, Dimension::XYZ) => {
             geometry_types.insert(MultiPointZ);
         }
-        NativeType::MultiLineString(_, Dimension::XY) => {
+        NativeType::MultiLineString(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiLineString);
         }
         NativeType::MultiLineString(_, Dimension::XYZ) => {
             geometry_types.insert(MultiLineStringZ);
         }
-        NativeType::MultiPolygon(_, Dimension::XY) => {
+        NativeType::MultiPolygon(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiPolygon);
         }
         NativeType::MultiPolygon(_, Dimension::XYZ) => {
@@ -258,7 +261,7 @@ pub fn get_geometry_types(data_type: &NativeType) -> HashSet<GeoParquetGeometryT
         NativeType::Mixed(_, _) => {
             // We don't have access to the actual data here, so we can't inspect better than this.
         }
-        NativeType::GeometryCollection(_, Dimension::XY) => {
+        NativeType::GeometryCollection(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(GeometryCollection);
         }
         NativeType::GeometryCollection(_, Dimension::XYZ) => {
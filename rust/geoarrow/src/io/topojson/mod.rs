@@ -0,0 +1,5 @@
+//! Read from [TopoJSON](https://github.com/topojson/topojson-specification) files.
+
+pub use reader::from_topojson;
+
+mod reader;
@@ -0,0 +1,344 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use geo::{coord, Coord};
+use geozero::{ColumnValue, FeatureProcessor};
+use serde_json::{Map, Value};
+
+use crate::array::MixedGeometryArray;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geozero::table::builder::properties::PropertiesBatchBuilder;
+use crate::table::Table;
+
+/// Read the object named `object_name` of a TopoJSON topology into a Table.
+///
+/// If the object is a `GeometryCollection`, each of its geometries becomes one row, with its
+/// `properties` as attribute columns. Otherwise the object itself is read as a single row. Arcs
+/// are resolved into explicit coordinates, so arcs shared between geometries are duplicated in
+/// each of them, and quantized topologies are decoded with their `transform`.
+pub fn from_topojson(reader: impl Read, object_name: &str) -> Result<Table> {
+    let topology: Value = serde_json::from_reader(reader)?;
+    if topology.get("type").and_then(Value::as_str) != Some("Topology") {
+        return Err(invalid("expected an object of type Topology"));
+    }
+
+    let transform = topology
+        .get("transform")
+        .map(Transform::from_json)
+        .transpose()?;
+    let arcs = topology
+        .get("arcs")
+        .and_then(Value::as_array)
+        .ok_or_else(|| invalid("missing arcs"))?
+        .iter()
+        .map(|arc| decode_arc(arc, transform.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let topology_ref = Topology {
+        arcs,
+        transform: transform.as_ref(),
+    };
+
+    let object = topology
+        .get("objects")
+        .and_then(|objects| objects.get(object_name))
+        .ok_or_else(|| invalid(&format!("no object named {object_name}")))?;
+    let members = match object.get("type").and_then(Value::as_str) {
+        Some("GeometryCollection") => object
+            .get("geometries")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid("missing geometries"))?
+            .iter()
+            .collect::<Vec<_>>(),
+        _ => vec![object],
+    };
+
+    let mut properties = PropertiesBatchBuilder::new();
+    let mut geometries = Vec::with_capacity(members.len());
+    for (idx, member) in members.into_iter().enumerate() {
+        if let Some(member_properties) = member.get("properties").and_then(Value::as_object) {
+            add_properties(&mut properties, member_properties)?;
+        }
+        properties.properties_end()?;
+        properties.feature_end(idx as u64)?;
+        geometries.push(topology_ref.geometry(member)?);
+    }
+
+    let batch = properties.finish()?;
+    let geometry: MixedGeometryArray = (geometries, Dimension::XY).try_into()?;
+    Table::from_arrow_and_geometry(
+        vec![batch.clone()],
+        batch.schema(),
+        Arc::new(ChunkedGeometryArray::new(vec![geometry])),
+    )
+}
+
+fn invalid(message: &str) -> GeoArrowError {
+    GeoArrowError::General(format!("Invalid TopoJSON: {message}"))
+}
+
+/// The transform of a quantized topology.
+struct Transform {
+    scale: [f64; 2],
+    translate: [f64; 2],
+}
+
+impl Transform {
+    fn from_json(value: &Value) -> Result<Self> {
+        let pair = |key: &str| -> Result<[f64; 2]> {
+            match value.get(key).and_then(Value::as_array).map(Vec::as_slice) {
+                Some([a, b]) => Ok([
+                    a.as_f64().ok_or_else(|| invalid(key))?,
+                    b.as_f64().ok_or_else(|| invalid(key))?,
+                ]),
+                _ => Err(invalid(&format!(
+                    "transform must have a {key} of two numbers"
+                ))),
+            }
+        };
+        Ok(Self {
+            scale: pair("scale")?,
+            translate: pair("translate")?,
+        })
+    }
+
+    fn apply(&self, x: f64, y: f64) -> Coord {
+        coord! {
+            x: x * self.scale[0] + self.translate[0],
+            y: y * self.scale[1] + self.translate[1],
+        }
+    }
+}
+
+fn read_position(value: &Value) -> Result<(f64, f64)> {
+    match value.as_array().map(Vec::as_slice) {
+        Some([x, y, ..]) => Ok((
+            x.as_f64().ok_or_else(|| invalid("non-numeric position"))?,
+            y.as_f64().ok_or_else(|| invalid("non-numeric position"))?,
+        )),
+        _ => Err(invalid("positions must have at least two numbers")),
+    }
+}
+
+/// Decode an arc, which is delta-encoded if the topology is quantized.
+fn decode_arc(arc: &Value, transform: Option<&Transform>) -> Result<Vec<Coord>> {
+    let positions = arc
+        .as_array()
+        .ok_or_else(|| invalid("arcs must be arrays"))?;
+    let (mut x, mut y) = (0., 0.);
+    positions
+        .iter()
+        .map(|position| {
+            let (dx, dy) = read_position(position)?;
+            Ok(match transform {
+                Some(transform) => {
+                    x += dx;
+                    y += dy;
+                    transform.apply(x, y)
+                }
+                None => coord! { x: dx, y: dy },
+            })
+        })
+        .collect()
+}
+
+struct Topology<'a> {
+    arcs: Vec<Vec<Coord>>,
+    transform: Option<&'a Transform>,
+}
+
+impl Topology<'_> {
+    fn point(&self, value: &Value) -> Result<geo::Point> {
+        let (x, y) = read_position(value)?;
+        Ok(match self.transform {
+            Some(transform) => transform.apply(x, y),
+            None => coord! { x: x, y: y },
+        }
+        .into())
+    }
+
+    /// Join a sequence of arc indexes into one line, dropping the first coordinate of each arc
+    /// after the first since it repeats the end of the previous arc.
+    fn line(&self, indexes: &Value) -> Result<geo::LineString> {
+        let indexes = indexes
+            .as_array()
+            .ok_or_else(|| invalid("expected an array of arc indexes"))?;
+        let mut coords: Vec<Coord> = vec![];
+        for index in indexes {
+            let index = index
+                .as_i64()
+                .ok_or_else(|| invalid("arc indexes must be integers"))?;
+            // Negative indexes refer to the arc !index, reversed
+            let (arc_index, reversed) = if index < 0 {
+                (!index, true)
+            } else {
+                (index, false)
+            };
+            let arc = self
+                .arcs
+                .get(arc_index as usize)
+                .ok_or_else(|| invalid(&format!("arc index {index} out of range")))?;
+            let skip = if coords.is_empty() { 0 } else { 1 };
+            if reversed {
+                coords.extend(arc.iter().rev().skip(skip));
+            } else {
+                coords.extend(arc.iter().skip(skip));
+            }
+        }
+        Ok(geo::LineString::new(coords))
+    }
+
+    fn polygon(&self, rings: &Value) -> Result<geo::Polygon> {
+        let mut rings = array(rings)?
+            .iter()
+            .map(|ring| self.line(ring))
+            .collect::<Result<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(geo::Polygon::new(geo::LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(geo::Polygon::new(exterior, rings))
+    }
+
+    fn geometry(&self, object: &Value) -> Result<Option<geo::Geometry>> {
+        let coordinates = || {
+            object
+                .get("coordinates")
+                .ok_or_else(|| invalid("missing coordinates"))
+        };
+        let arcs = || object.get("arcs").ok_or_else(|| invalid("missing arcs"));
+
+        let geometry = match object.get("type").and_then(Value::as_str) {
+            None => return Ok(None),
+            Some("Point") => geo::Geometry::Point(self.point(coordinates()?)?),
+            Some("MultiPoint") => geo::Geometry::MultiPoint(
+                array(coordinates()?)?
+                    .iter()
+                    .map(|point| self.point(point))
+                    .collect::<Result<Vec<_>>>()?
+                    .into(),
+            ),
+            Some("LineString") => geo::Geometry::LineString(self.line(arcs()?)?),
+            Some("MultiLineString") => geo::Geometry::MultiLineString(geo::MultiLineString::new(
+                array(arcs()?)?
+                    .iter()
+                    .map(|line| self.line(line))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            Some("Polygon") => geo::Geometry::Polygon(self.polygon(arcs()?)?),
+            Some("MultiPolygon") => geo::Geometry::MultiPolygon(geo::MultiPolygon::new(
+                array(arcs()?)?
+                    .iter()
+                    .map(|polygon| self.polygon(polygon))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            Some("GeometryCollection") => {
+                let mut geometries = vec![];
+                for member in array(
+                    object
+                        .get("geometries")
+                        .ok_or_else(|| invalid("missing geometries"))?,
+                )? {
+                    geometries.extend(self.geometry(member)?);
+                }
+                geo::Geometry::GeometryCollection(geo::GeometryCollection::from(geometries))
+            }
+            Some(other) => return Err(invalid(&format!("unknown geometry type {other}"))),
+        };
+        Ok(Some(geometry))
+    }
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>> {
+    value.as_array().ok_or_else(|| invalid("expected an array"))
+}
+
+/// Add the properties of one feature, following the conversion of geozero's GeoJSON reader.
+fn add_properties(
+    builder: &mut PropertiesBatchBuilder,
+    properties: &Map<String, Value>,
+) -> Result<()> {
+    for (key, value) in properties.iter() {
+        match value {
+            Value::String(v) => builder.add_single_property(key, &ColumnValue::String(v))?,
+            Value::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    builder.add_single_property(key, &ColumnValue::Long(v))?
+                } else if let Some(v) = v.as_u64() {
+                    builder.add_single_property(key, &ColumnValue::ULong(v))?
+                } else {
+                    builder.add_single_property(key, &ColumnValue::Double(v.as_f64().unwrap()))?
+                }
+            }
+            Value::Bool(v) => builder.add_single_property(key, &ColumnValue::Bool(*v))?,
+            Value::Array(_) | Value::Object(_) => {
+                let json_string = serde_json::to_string(value)?;
+                builder.add_single_property(key, &ColumnValue::Json(&json_string))?
+            }
+            // For null values omit the property
+            Value::Null => (),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsChunkedNativeArray;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn shared_edge() {
+        // Two unit squares side by side, sharing the edge from (1, 0) to (1, 1), which is arc 1.
+        // The topology is quantized with a scale of 1, so arcs are delta-encoded.
+        let topojson = r#"{
+            "type": "Topology",
+            "transform": {"scale": [1, 1], "translate": [0, 0]},
+            "objects": {
+                "squares": {
+                    "type": "GeometryCollection",
+                    "geometries": [
+                        {"type": "Polygon", "arcs": [[0, 1]], "properties": {"name": "left"}},
+                        {"type": "Polygon", "arcs": [[2, -2]], "properties": {"name": "right"}}
+                    ]
+                }
+            },
+            "arcs": [
+                [[1, 1], [-1, 0], [0, -1], [1, 0]],
+                [[1, 0], [0, 1]],
+                [[1, 1], [1, 0], [0, -1], [-1, 0]]
+            ]
+        }"#;
+        let table = from_topojson(topojson.as_bytes(), "squares").unwrap();
+        assert_eq!(table.len(), 2);
+
+        let geometry = table.geometry().unwrap();
+        let chunk = &geometry.as_ref().as_mixed().chunks()[0];
+        let as_polygon = |geom: geo::Geometry| match geom {
+            geo::Geometry::Polygon(polygon) => polygon,
+            geo::Geometry::MultiPolygon(mut multi_polygon) if multi_polygon.0.len() == 1 => {
+                multi_polygon.0.remove(0)
+            }
+            other => panic!("unexpected geometry {other:?}"),
+        };
+
+        let left = geo::polygon![
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        let right = geo::polygon![
+            (x: 1., y: 1.),
+            (x: 2., y: 1.),
+            (x: 2., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        assert_eq!(as_polygon(chunk.value_as_geo(0)), left);
+        assert_eq!(as_polygon(chunk.value_as_geo(1)), right);
+    }
+}
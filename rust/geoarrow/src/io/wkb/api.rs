@@ -1,15 +1,27 @@
 use std::sync::Arc;
 
 use crate::algorithm::native::Downcast;
+use crate::array::binary::dialect::{EWKB_M_FLAG, EWKB_SRID_FLAG, EWKB_Z_FLAG};
 use crate::array::geometrycollection::GeometryCollectionBuilder;
 use crate::array::*;
 use crate::chunked_array::*;
-use crate::datatypes::{Dimension, NativeType};
+use crate::datatypes::{Dimension, GeometryType, NativeType};
 use crate::error::{GeoArrowError, Result};
-use crate::scalar::WKB;
+use crate::scalar::{GeometryScalar, WKB};
 use crate::trait_::ArrayAccessor;
-use crate::NativeArray;
-use arrow_array::OffsetSizeTrait;
+use crate::{ArrayBase, NativeArray};
+use arrow_array::{BinaryArray, OffsetSizeTrait, UInt32Array};
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use wkb::writer::{
+    geometry_collection_wkb_size, geometry_wkb_size, line_string_wkb_size,
+    multi_line_string_wkb_size, multi_point_wkb_size, multi_polygon_wkb_size, point_wkb_size,
+    polygon_wkb_size,
+};
 
 /// An optimized implementation of converting from ISO WKB-encoded geometries.
 ///
@@ -183,20 +195,120 @@ pub fn from_wkb<O: OffsetSizeTrait>(
     arr: &WKBArray<O>,
     target_geo_data_type: NativeType,
     prefer_multi: bool,
+) -> Result<Arc<dyn NativeArray>> {
+    let wkb_objects: Vec<Option<WKB<'_, O>>> = arr.iter().collect();
+    build_from_wkb(&wkb_objects, arr, target_geo_data_type, prefer_multi)
+}
+
+/// How to handle geometries with non-finite (NaN or infinite) coordinates when parsing WKB.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Parse non-finite coordinates as is.
+    #[default]
+    Keep,
+    /// Return an error if any geometry has a non-finite coordinate.
+    Error,
+    /// Replace any geometry that has a non-finite coordinate with null.
+    NullOut,
+}
+
+/// Parse an ISO [WKBArray] to a GeometryArray with GeoArrow native encoding, handling non-finite
+/// coordinates according to `nan_policy`.
+///
+/// WKB has no representation of an empty point, so writers commonly encode one as a point with NaN
+/// coordinates. Such points are treated as non-finite here.
+///
+/// With [`NanPolicy::Keep`], this is equivalent to [`from_wkb`]. Does not downcast automatically
+pub fn from_wkb_with_nan_policy<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    target_geo_data_type: NativeType,
+    prefer_multi: bool,
+    nan_policy: NanPolicy,
+) -> Result<Arc<dyn NativeArray>> {
+    let mut wkb_objects: Vec<Option<WKB<'_, O>>> = arr.iter().collect();
+    if nan_policy != NanPolicy::Keep {
+        for (i, maybe_wkb) in wkb_objects.iter_mut().enumerate() {
+            let is_finite = match maybe_wkb {
+                Some(wkb) => geometry_is_finite(&wkb.parse()?),
+                None => true,
+            };
+            if !is_finite {
+                match nan_policy {
+                    NanPolicy::Error => {
+                        return Err(GeoArrowError::General(format!(
+                            "Geometry at index {i} has non-finite coordinates"
+                        )))
+                    }
+                    NanPolicy::NullOut => *maybe_wkb = None,
+                    NanPolicy::Keep => unreachable!(),
+                }
+            }
+        }
+    }
+    build_from_wkb(&wkb_objects, arr, target_geo_data_type, prefer_multi)
+}
+
+fn coord_is_finite(coord: &impl CoordTrait<T = f64>) -> bool {
+    (0..coord.dim().size()).all(|n| coord.nth_or_panic(n).is_finite())
+}
+
+fn line_string_is_finite(line_string: &impl LineStringTrait<T = f64>) -> bool {
+    line_string.coords().all(|coord| coord_is_finite(&coord))
+}
+
+fn polygon_is_finite(polygon: &impl PolygonTrait<T = f64>) -> bool {
+    polygon
+        .exterior()
+        .into_iter()
+        .chain(polygon.interiors())
+        .all(|ring| line_string_is_finite(&ring))
+}
+
+fn point_is_finite(point: &impl PointTrait<T = f64>) -> bool {
+    // An empty point is written to WKB with NaN coordinates
+    point.coord().is_some_and(|coord| coord_is_finite(&coord))
+}
+
+/// Returns `false` if any coordinate of `geom` is NaN or infinite.
+fn geometry_is_finite(geom: &impl GeometryTrait<T = f64>) -> bool {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(g) => point_is_finite(g),
+        LineString(g) => line_string_is_finite(g),
+        Polygon(g) => polygon_is_finite(g),
+        MultiPoint(g) => g.points().all(|point| point_is_finite(&point)),
+        MultiLineString(g) => g.line_strings().all(|ls| line_string_is_finite(&ls)),
+        MultiPolygon(g) => g.polygons().all(|polygon| polygon_is_finite(&polygon)),
+        GeometryCollection(g) => g.geometries().all(|child| geometry_is_finite(&child)),
+        Rect(g) => coord_is_finite(&g.min()) && coord_is_finite(&g.max()),
+        Triangle(g) => {
+            coord_is_finite(&g.first())
+                && coord_is_finite(&g.second())
+                && coord_is_finite(&g.third())
+        }
+        Line(g) => coord_is_finite(&g.start()) && coord_is_finite(&g.end()),
+    }
+}
+
+fn build_from_wkb<O: OffsetSizeTrait>(
+    wkb_objects: &[Option<WKB<'_, O>>],
+    arr: &WKBArray<O>,
+    target_geo_data_type: NativeType,
+    prefer_multi: bool,
 ) -> Result<Arc<dyn NativeArray>> {
     use NativeType::*;
     let target_dim = target_geo_data_type.dimension();
 
-    let wkb_objects: Vec<Option<crate::scalar::WKB<'_, O>>> = arr.iter().collect();
     match target_geo_data_type {
         Point(coord_type, _) => {
             let builder =
-                PointBuilder::from_wkb(&wkb_objects, target_dim, Some(coord_type), arr.metadata())?;
+                PointBuilder::from_wkb(wkb_objects, target_dim, Some(coord_type), arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         LineString(coord_type, _) => {
             let builder = LineStringBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -205,7 +317,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         Polygon(coord_type, _) => {
             let builder = PolygonBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -214,7 +326,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         MultiPoint(coord_type, _) => {
             let builder = MultiPointBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -223,7 +335,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         MultiLineString(coord_type, _) => {
             let builder = MultiLineStringBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -232,7 +344,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         MultiPolygon(coord_type, _) => {
             let builder = MultiPolygonBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -241,7 +353,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         Mixed(coord_type, _) => {
             let builder = MixedGeometryBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -251,7 +363,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         }
         GeometryCollection(coord_type, _) => {
             let builder = GeometryCollectionBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 target_dim,
                 Some(coord_type),
                 arr.metadata(),
@@ -266,6 +378,126 @@ pub fn from_wkb<O: OffsetSizeTrait>(
     }
 }
 
+/// Read the byte order and geometry type word from the header of a WKB geometry.
+///
+/// Returns whether the geometry is little endian, along with the raw type word.
+fn wkb_header(buf: &[u8]) -> Result<(bool, u32)> {
+    let (byte_order, type_bytes) = match (buf.first(), buf.get(1..5)) {
+        (Some(byte_order), Some(type_bytes)) => (*byte_order, type_bytes.try_into().unwrap()),
+        _ => return Err(GeoArrowError::General("WKB buffer too short".to_string())),
+    };
+    match byte_order {
+        0 => Ok((false, u32::from_be_bytes(type_bytes))),
+        1 => Ok((true, u32::from_le_bytes(type_bytes))),
+        _ => Err(GeoArrowError::General(format!(
+            "Invalid WKB byte order {byte_order}"
+        ))),
+    }
+}
+
+/// Read the geometry type from the header of an ISO or extended WKB geometry.
+fn wkb_geometry_type(buf: &[u8]) -> Result<GeometryType> {
+    let (_, type_id) = wkb_header(buf)?;
+
+    // Strip EWKB flags and ISO dimension offsets
+    match (type_id & 0x0FFF_FFFF) % 1000 {
+        1 => Ok(GeometryType::Point),
+        2 => Ok(GeometryType::LineString),
+        3 => Ok(GeometryType::Polygon),
+        4 => Ok(GeometryType::MultiPoint),
+        5 => Ok(GeometryType::MultiLineString),
+        6 => Ok(GeometryType::MultiPolygon),
+        7 => Ok(GeometryType::GeometryCollection),
+        _ => Err(GeoArrowError::General(format!(
+            "Unsupported WKB geometry type {type_id}"
+        ))),
+    }
+}
+
+/// Detect whether a [WKBArray] holds ISO WKB or extended WKB (EWKB).
+///
+/// Only the header of the first non-null geometry is inspected. EWKB is reported if its type has
+/// any of the EWKB Z, M, or SRID flags set, along with the SRID if one is present. Otherwise the
+/// array is reported as ISO WKB, which includes plain 2D WKB and arrays with no non-null
+/// geometries. Note that 2D EWKB without an SRID is byte-for-byte identical to ISO WKB.
+pub fn detect_wkb_dialect<O: OffsetSizeTrait>(arr: &WKBArray<O>) -> Result<WkbDialect> {
+    let Some(wkb) = arr.iter().flatten().next() else {
+        return Ok(WkbDialect::Iso);
+    };
+    let buf = wkb.as_ref();
+    let (little_endian, type_id) = wkb_header(buf)?;
+    if type_id & (EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG) == 0 {
+        return Ok(WkbDialect::Iso);
+    }
+
+    let srid = if type_id & EWKB_SRID_FLAG != 0 {
+        let srid_bytes: [u8; 4] = buf
+            .get(5..9)
+            .ok_or(GeoArrowError::General("WKB buffer too short".to_string()))?
+            .try_into()
+            .unwrap();
+        let srid = if little_endian {
+            i32::from_le_bytes(srid_bytes)
+        } else {
+            i32::from_be_bytes(srid_bytes)
+        };
+        Some(srid)
+    } else {
+        None
+    };
+    Ok(WkbDialect::Ewkb { srid })
+}
+
+/// Parse only the geometries of an ISO [WKBArray] whose type is in `keep`.
+///
+/// Only the header of each geometry is read to decide whether to keep it, so geometries that are
+/// dropped are never fully parsed. Null geometries are always dropped.
+///
+/// Returns the parsed geometries, downcast to the narrowest array type that holds them, along with
+/// the index in `arr` of each kept geometry.
+pub fn from_wkb_filtered<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    keep: &[GeometryType],
+    coord_type: CoordType,
+    dim: Dimension,
+    prefer_multi: bool,
+) -> Result<(Arc<dyn NativeArray>, UInt32Array)> {
+    let mut wkb_objects = vec![];
+    let mut indices = vec![];
+    for (i, maybe_wkb) in arr.iter().enumerate() {
+        if let Some(wkb) = maybe_wkb {
+            if keep.contains(&wkb_geometry_type(wkb.as_ref())?) {
+                indices.push(i as u32);
+                wkb_objects.push(Some(wkb));
+            }
+        }
+    }
+
+    let builder = GeometryCollectionBuilder::from_wkb(
+        &wkb_objects,
+        dim,
+        Some(coord_type),
+        arr.metadata(),
+        prefer_multi,
+    )?;
+    Ok((builder.finish().downcast(true), UInt32Array::from(indices)))
+}
+
+/// Scan a [WKBArray], counting the geometries and coordinates of each geometry type.
+///
+/// Each WKB geometry is parsed once. The resulting [`GeometryCapacity`] can then be used to
+/// pre-size the appropriate builder before parsing the array into GeoArrow native encoding.
+pub fn wkb_capacity<O: OffsetSizeTrait>(arr: &WKBArray<O>) -> Result<GeometryCapacity> {
+    let mut capacity = GeometryCapacity::new_empty();
+    for maybe_wkb in arr.iter() {
+        match maybe_wkb {
+            Some(wkb) => capacity.add_geometry(Some(&wkb.parse()?))?,
+            None => capacity.add_geometry(None::<&geo::Geometry>)?,
+        }
+    }
+    Ok(capacity)
+}
+
 /// An optimized implementation of converting from ISO WKB-encoded geometries.
 ///
 /// This implementation performs a two-pass approach, first scanning the input geometries to
@@ -345,10 +577,96 @@ pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn NativeArray) -> WKBArray<O> {
     }
 }
 
+/// Parse each chunk of an ISO [ChunkedWKBArray] to the given native type in parallel.
+///
+/// Unlike [`FromWKB`] for `Arc<dyn ChunkedNativeArray>`, this does not downcast each chunk, so
+/// every chunk of the output has the same `target_geo_data_type`. Chunks are kept in their
+/// original order.
+#[cfg(feature = "rayon")]
+pub fn par_from_wkb<O: OffsetSizeTrait>(
+    arr: &ChunkedWKBArray<O>,
+    target_geo_data_type: NativeType,
+    prefer_multi: bool,
+) -> Result<Arc<dyn ChunkedNativeArray>> {
+    use rayon::prelude::*;
+
+    let chunks = arr
+        .chunks()
+        .par_iter()
+        .map(|chunk| from_wkb(chunk, target_geo_data_type, prefer_multi))
+        .collect::<Result<Vec<_>>>()?;
+    let chunk_refs = chunks
+        .iter()
+        .map(|chunk| chunk.as_ref())
+        .collect::<Vec<_>>();
+    Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+}
+
+/// Parse a single ISO WKB-encoded geometry into a [`geo::Geometry`].
+pub fn wkb_to_geometry(bytes: &[u8]) -> Result<geo::Geometry> {
+    Ok(wkb::reader::read_wkb(bytes)?.to_geometry())
+}
+
+/// Encode a single geometry as little-endian ISO WKB.
+///
+/// This is the scalar counterpart to [`to_wkb`], and the inverse of [`wkb_to_geometry`]. Rects and
+/// triangles are encoded as polygons, and lines as line strings.
+pub fn geometry_to_wkb(geom: &impl GeometryTrait<T = f64>) -> Vec<u8> {
+    let mut builder = WKBBuilder::<i32>::with_capacity_from_iter(std::iter::once(Some(geom)));
+    builder.push_geometry(Some(geom));
+    builder.finish().into_inner().value(0).to_vec()
+}
+
+/// Parse a single ISO WKB-encoded geometry into a [`GeometryScalar`] of the given type.
+///
+/// This is equivalent to calling [`from_wkb`] on a one-element [`WKBArray`].
+pub fn wkb_to_geoarrow_scalar(bytes: &[u8], target: NativeType) -> Result<GeometryScalar> {
+    let binary_arr = BinaryArray::from_vec(vec![bytes]);
+    let wkb_arr = WKBArray::new(binary_arr, Default::default());
+    GeometryScalar::try_new(from_wkb(&wkb_arr, target, false)?)
+}
+
+/// Compute the total number of bytes [`to_wkb`] would write for the geometries in `arr`.
+///
+/// The size is derived from coordinate and part counts alone, without encoding any geometries. It
+/// covers the values buffer of the output only, not its offsets or validity buffers.
+pub fn wkb_output_size(arr: &dyn NativeArray) -> usize {
+    use NativeType::*;
+
+    macro_rules! impl_size {
+        ($method:ident, $size_func:ident) => {
+            arr.$method()
+                .iter()
+                .flatten()
+                .map(|geom| $size_func(&geom))
+                .sum()
+        };
+    }
+
+    match arr.data_type() {
+        Point(_, dim) => (arr.len() - arr.null_count()) * point_wkb_size(dim.into()),
+        LineString(_, _) => impl_size!(as_line_string, line_string_wkb_size),
+        Polygon(_, _) => impl_size!(as_polygon, polygon_wkb_size),
+        MultiPoint(_, _) => impl_size!(as_multi_point, multi_point_wkb_size),
+        MultiLineString(_, _) => impl_size!(as_multi_line_string, multi_line_string_wkb_size),
+        MultiPolygon(_, _) => impl_size!(as_multi_polygon, multi_polygon_wkb_size),
+        Mixed(_, _) => impl_size!(as_mixed, geometry_wkb_size),
+        GeometryCollection(_, _) => {
+            impl_size!(as_geometry_collection, geometry_collection_wkb_size)
+        }
+        Rect(dim) => {
+            // Each rect is written as a polygon with a single ring of 5 coordinates: a byte order,
+            // geometry type, ring count, and coordinate count, followed by the coordinates
+            let rect_size = 1 + 4 + 4 + 4 + 5 * dim.size() * 8;
+            (arr.len() - arr.null_count()) * rect_size
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test::point;
+    use crate::test::{linestring, point, polygon};
 
     #[test]
     fn point_round_trip_explicit_casting() {
@@ -407,4 +725,277 @@ mod test {
         let rt_arr = rt_ref.as_point();
         assert_eq!(rt_arr, &arr);
     }
+
+    #[test]
+    fn wkb_capacity_mixed() {
+        let geoms = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            Some(geo::Geometry::Polygon(polygon::p0())),
+            None,
+            Some(geo::Geometry::Polygon(polygon::p1())),
+        ];
+        let wkb_arr: WKBArray<i32> = WKBBuilder::from_nullable_geometries(&geoms).finish();
+        let capacity = wkb_capacity(&wkb_arr).unwrap();
+
+        assert_eq!(capacity.null_count(), 1);
+        assert_eq!(capacity.total_num_geoms(), 5);
+
+        let mixed = capacity.mixed_capacity();
+        assert_eq!(mixed.point_capacity(), 1);
+        assert_eq!(mixed.line_string_capacity().geom_capacity(), 1);
+        assert_eq!(mixed.line_string_capacity().coord_capacity(), 2);
+        assert_eq!(mixed.polygon_capacity().geom_capacity(), 2);
+        assert_eq!(mixed.polygon_capacity().ring_capacity(), 3);
+        assert_eq!(mixed.polygon_capacity().coord_capacity(), 15);
+        assert!(mixed.multi_point_capacity().is_empty());
+        assert!(capacity.geometry_collection_capacity().is_empty());
+    }
+
+    #[test]
+    fn wkb_output_size_matches_to_wkb() {
+        let arr: MixedGeometryArray = (
+            vec![
+                Some(geo::Geometry::Point(point::p0())),
+                Some(geo::Geometry::LineString(linestring::ls0())),
+                Some(geo::Geometry::Polygon(polygon::p1())),
+            ],
+            Dimension::XY,
+        )
+            .try_into()
+            .unwrap();
+
+        let wkb_arr: WKBArray<i32> = to_wkb(&arr);
+        assert_eq!(wkb_output_size(&arr), wkb_arr.into_inner().values().len());
+
+        let point_arr = point::point_z_array();
+        let wkb_arr: WKBArray<i32> = to_wkb(&point_arr);
+        assert_eq!(
+            wkb_output_size(&point_arr),
+            wkb_arr.into_inner().values().len()
+        );
+
+        let rect_arr: RectArray = RectBuilder::from((
+            vec![Some(geo::Rect::new((0., 0.), (2., 1.))), None],
+            Dimension::XY,
+        ))
+        .finish();
+        let wkb_arr: WKBArray<i32> = to_wkb(&PolygonArray::from(rect_arr.clone()));
+        assert_eq!(
+            wkb_output_size(&rect_arr),
+            wkb_arr.into_inner().values().len()
+        );
+    }
+
+    fn point_wkb() -> Vec<u8> {
+        let mut bytes = vec![0x01, 0x01, 0x00, 0x00, 0x00];
+        bytes.extend_from_slice(&1.0_f64.to_le_bytes());
+        bytes.extend_from_slice(&2.0_f64.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn wkb_to_geometry_point() {
+        let geom = wkb_to_geometry(&point_wkb()).unwrap();
+        assert_eq!(geom, geo::Geometry::Point(geo::point!(x: 1., y: 2.)));
+    }
+
+    #[test]
+    fn wkb_to_geoarrow_scalar_point() {
+        let target = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+        let scalar = wkb_to_geoarrow_scalar(&point_wkb(), target).unwrap();
+        assert_eq!(scalar.data_type(), target);
+
+        let point: geo::Point = scalar.inner().as_ref().as_point().value(0).into();
+        assert_eq!(point, geo::point!(x: 1., y: 2.));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_from_wkb_matches_serial() {
+        let chunks: Vec<WKBArray<i32>> =
+            vec![to_wkb(&point::point_array()), to_wkb(&point::point_array())];
+        let chunked = ChunkedWKBArray::new(chunks);
+        let target = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+
+        let parallel = par_from_wkb(&chunked, target, false).unwrap();
+        assert_eq!(parallel.data_type(), target);
+
+        let parallel_chunks = parallel.as_ref().as_point().chunks();
+        assert_eq!(parallel_chunks.len(), chunked.chunks().len());
+        for (parallel_chunk, wkb_chunk) in parallel_chunks.iter().zip(chunked.chunks()) {
+            let serial = from_wkb(wkb_chunk, target, false).unwrap();
+            assert_eq!(parallel_chunk, serial.as_ref().as_point());
+        }
+    }
+
+    #[test]
+    fn from_wkb_filtered_points() {
+        let geoms = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            None,
+            Some(geo::Geometry::Point(point::p1())),
+            Some(geo::Geometry::Polygon(polygon::p0())),
+        ];
+        let wkb_arr: WKBArray<i32> = WKBBuilder::from_nullable_geometries(&geoms).finish();
+
+        let (filtered, indices) = from_wkb_filtered(
+            &wkb_arr,
+            &[GeometryType::Point],
+            CoordType::Interleaved,
+            Dimension::XY,
+            false,
+        )
+        .unwrap();
+        assert_eq!(indices.values().as_ref(), &[0, 3]);
+
+        let points = filtered.as_ref().as_point();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points.value_as_geo(0), point::p0());
+        assert_eq!(points.value_as_geo(1), point::p1());
+    }
+
+    #[test]
+    fn geometry_to_wkb_round_trip() {
+        let geoms: Vec<geo::Geometry> = vec![
+            geo::point!(x: 1., y: 2.).into(),
+            geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)].into(),
+            geo::GeometryCollection::new_from(vec![
+                geo::point!(x: 3., y: 4.).into(),
+                geo::line_string![(x: 5., y: 5.), (x: 6., y: 6.)].into(),
+            ])
+            .into(),
+        ];
+        for geom in geoms {
+            let buf = geometry_to_wkb(&geom);
+            assert_eq!(buf.len(), geometry_wkb_size(&geom));
+            assert_eq!(wkb_to_geometry(&buf).unwrap(), geom);
+        }
+    }
+
+    #[test]
+    fn geometry_to_wkb_rect_line_triangle() {
+        let rect = geo::Rect::new((0., 0.), (2., 1.));
+        let buf = geometry_to_wkb(&geo::Geometry::Rect(rect));
+        assert_eq!(wkb_to_geometry(&buf).unwrap(), rect.to_polygon().into());
+
+        let triangle = geo::Triangle::new((0., 0.).into(), (1., 0.).into(), (0., 1.).into());
+        let buf = geometry_to_wkb(&geo::Geometry::Triangle(triangle));
+        assert_eq!(wkb_to_geometry(&buf).unwrap(), triangle.to_polygon().into());
+
+        let line = geo::Line::new((0., 0.), (3., 4.));
+        let buf = geometry_to_wkb(&geo::Geometry::Line(line));
+        assert_eq!(
+            wkb_to_geometry(&buf).unwrap(),
+            geo::LineString::from(line).into()
+        );
+    }
+
+    fn nan_wkb_array() -> WKBArray<i32> {
+        let geoms: Vec<Option<geo::LineString>> = vec![
+            Some(geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            Some(geo::line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)]),
+            None,
+        ];
+        WKBArray::try_from(geoms).unwrap()
+    }
+
+    #[test]
+    fn nan_policy() {
+        let wkb_arr = nan_wkb_array();
+        let target = NativeType::LineString(CoordType::Interleaved, Dimension::XY);
+
+        let kept = from_wkb_with_nan_policy(&wkb_arr, target, false, NanPolicy::Keep).unwrap();
+        let kept = kept.as_ref().as_line_string();
+        assert!(kept.is_valid(1));
+        assert!(kept.value_as_geo(1).0[1].x.is_nan());
+
+        let err = from_wkb_with_nan_policy(&wkb_arr, target, false, NanPolicy::Error);
+        assert!(err.is_err());
+
+        let nulled = from_wkb_with_nan_policy(&wkb_arr, target, false, NanPolicy::NullOut).unwrap();
+        let nulled = nulled.as_ref().as_line_string();
+        assert_eq!(nulled.len(), 3);
+        assert!(nulled.is_valid(0));
+        assert!(nulled.is_null(1));
+        assert!(nulled.is_null(2));
+    }
+
+    #[test]
+    fn parse_error_row() {
+        let point = geometry_to_wkb(&geo::point!(x: 1., y: 2.));
+        let malformed = [1, 1, 0];
+        let binary_arr =
+            BinaryArray::from_vec(vec![point.as_slice(), point.as_slice(), &malformed[..]]);
+        let wkb_arr = WKBArray::new(binary_arr, Default::default());
+
+        let target = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+        let err = from_wkb(&wkb_arr, target, false).unwrap_err();
+        assert!(matches!(err, GeoArrowError::ParseError { row: 2, .. }));
+        assert!(err.to_string().contains("row 2"));
+    }
+
+    #[test]
+    fn xym_point() {
+        // ISO WKB for POINT M (1 2 3), little endian
+        let mut buf = vec![1];
+        buf.extend_from_slice(&2001_u32.to_le_bytes());
+        for value in [1., 2., 3.] {
+            buf.extend_from_slice(&f64::to_le_bytes(value));
+        }
+        let wkb_arr = WKBArray::new(
+            BinaryArray::from_vec(vec![buf.as_slice()]),
+            Default::default(),
+        );
+
+        let target = NativeType::Point(CoordType::Interleaved, Dimension::XYM);
+        let arr = from_wkb(&wkb_arr, target, false).unwrap();
+        assert_eq!(arr.data_type(), target);
+
+        let point = arr.as_ref().as_point().value(0);
+        let coord = point.coord().unwrap();
+        assert_eq!(coord.dim(), geo_traits::Dimensions::Xym);
+        assert_eq!(coord.nth_or_panic(2), 3.);
+
+        // The measure must not be read as a Z value
+        let target = NativeType::Point(CoordType::Interleaved, Dimension::XYZ);
+        assert!(from_wkb(&wkb_arr, target, false).is_err());
+    }
+
+    #[test]
+    fn detect_dialect() {
+        // POINT (1 2) as ISO WKB, little endian
+        let mut iso = vec![1];
+        iso.extend_from_slice(&1_u32.to_le_bytes());
+        iso.extend_from_slice(&1_f64.to_le_bytes());
+        iso.extend_from_slice(&2_f64.to_le_bytes());
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![None, Some(iso.as_slice())]).into();
+        assert_eq!(detect_wkb_dialect(&wkb_arr).unwrap(), WkbDialect::Iso);
+
+        // SRID=4326;POINT (1 2) as EWKB, big endian
+        let mut ewkb = vec![0];
+        ewkb.extend_from_slice(&0x2000_0001_u32.to_be_bytes());
+        ewkb.extend_from_slice(&4326_i32.to_be_bytes());
+        ewkb.extend_from_slice(&1_f64.to_be_bytes());
+        ewkb.extend_from_slice(&2_f64.to_be_bytes());
+        let wkb_arr: WKBArray<i32> = BinaryArray::from_vec(vec![ewkb.as_slice()]).into();
+        assert_eq!(
+            detect_wkb_dialect(&wkb_arr).unwrap(),
+            WkbDialect::Ewkb { srid: Some(4326) }
+        );
+
+        // POINT Z (1 2 3) as EWKB without an SRID
+        let mut ewkb_z = vec![1];
+        ewkb_z.extend_from_slice(&0x8000_0001_u32.to_le_bytes());
+        for value in [1., 2., 3.] {
+            ewkb_z.extend_from_slice(&f64::to_le_bytes(value));
+        }
+        let wkb_arr: WKBArray<i32> = BinaryArray::from_vec(vec![ewkb_z.as_slice()]).into();
+        assert_eq!(
+            detect_wkb_dialect(&wkb_arr).unwrap(),
+            WkbDialect::Ewkb { srid: None }
+        );
+    }
 }
@@ -3,4 +3,10 @@
 mod api;
 pub(crate) mod writer;
 
-pub use api::{from_wkb, to_wkb, FromWKB, ToWKB};
+#[cfg(feature = "rayon")]
+pub use api::par_from_wkb;
+pub use api::{
+    detect_wkb_dialect, from_wkb, from_wkb_filtered, from_wkb_with_nan_policy, geometry_to_wkb,
+    to_wkb, wkb_capacity, wkb_output_size, wkb_to_geoarrow_scalar, wkb_to_geometry, FromWKB,
+    NanPolicy, ToWKB,
+};
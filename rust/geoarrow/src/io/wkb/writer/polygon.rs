@@ -44,9 +44,11 @@ impl<O: OffsetSizeTrait> From<&PolygonArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::PolygonBuilder;
     use crate::datatypes::Dimension;
     use crate::test::polygon::{p0, p1};
     use crate::trait_::ArrayAccessor;
+    use geo_traits::PolygonTrait;
     use geozero::{CoordDimensions, ToWkb};
 
     #[test]
@@ -67,4 +69,23 @@ mod test {
 
         assert_eq!(orig_arr, new_arr);
     }
+
+    #[test]
+    fn round_trip_empty() {
+        let mut builder = PolygonBuilder::new(Dimension::XY);
+        builder.push_polygon(Some(&p0())).unwrap();
+        builder.push_empty();
+        builder.push_null();
+        let orig_arr = builder.finish();
+
+        let wkb_arr: WKBArray<i32> = (&orig_arr).into();
+        // Little endian, polygon type id, zero rings
+        assert_eq!(wkb_arr.value(1).as_ref(), &[1, 3, 0, 0, 0, 0, 0, 0, 0]);
+
+        let new_arr: PolygonArray = (wkb_arr, Dimension::XY).try_into().unwrap();
+        assert!(new_arr.is_valid(1));
+        assert!(new_arr.value(1).exterior().is_none());
+        assert!(new_arr.is_null(2));
+        assert_eq!(orig_arr, new_arr);
+    }
 }
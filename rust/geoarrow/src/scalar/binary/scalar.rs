@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::NativeScalar;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use geo::BoundingRect;
@@ -33,8 +33,15 @@ impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
         (self.arr.clone(), self.geom_index)
     }
 
+    /// Parse this WKB object.
+    ///
+    /// If parsing fails, the returned [`GeoArrowError::ParseError`] names the row of this object
+    /// within its array.
     pub fn parse(&self) -> Result<impl GeometryTrait<T = f64> + use<'_, O>> {
-        Ok(wkb::reader::read_wkb(self.as_ref())?)
+        wkb::reader::read_wkb(self.as_ref()).map_err(|err| GeoArrowError::ParseError {
+            row: self.geom_index,
+            source: Box::new(err),
+        })
     }
 }
 
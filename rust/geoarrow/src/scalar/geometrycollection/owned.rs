@@ -67,6 +67,7 @@ impl GeometryCollectionTrait for OwnedGeometryCollection {
         match self.array.dimension() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
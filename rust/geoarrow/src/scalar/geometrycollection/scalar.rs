@@ -72,6 +72,7 @@ impl<'a> GeometryCollectionTrait for GeometryCollection<'a> {
         match self.array.dimension() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
@@ -96,6 +97,7 @@ impl<'a> GeometryCollectionTrait for &'a GeometryCollection<'a> {
         match self.array.dimension() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
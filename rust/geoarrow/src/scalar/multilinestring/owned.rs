@@ -81,6 +81,7 @@ impl MultiLineStringTrait for OwnedMultiLineString {
         match self.coords.dim() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
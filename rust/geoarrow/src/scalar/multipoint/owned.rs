@@ -62,6 +62,7 @@ impl MultiPointTrait for OwnedMultiPoint {
         match self.coords.dim() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
@@ -100,6 +100,7 @@ impl<'a> MultiPolygonTrait for MultiPolygon<'a> {
         match self.coords.dim() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
@@ -129,6 +130,7 @@ impl<'a> MultiPolygonTrait for &'a MultiPolygon<'a> {
         match self.coords.dim() {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
         }
     }
 
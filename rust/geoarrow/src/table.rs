@@ -14,7 +14,7 @@ use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedNativeArray, ChunkedNativeArrayDyn};
 use crate::datatypes::{AnyType, Dimension, NativeType, SerializedType};
 use crate::error::{GeoArrowError, Result};
-use crate::io::wkb::from_wkb;
+use crate::io::wkb::{from_wkb, to_wkb};
 use crate::schema::GeoSchemaExt;
 use phf::{phf_set, Set};
 
@@ -174,6 +174,51 @@ impl Table {
         Ok(())
     }
 
+    /// Consumes this table, returning a new table where every GeoArrow-native geometry column uses
+    /// the provided [`CoordType`].
+    ///
+    /// All chunks of each geometry column are converted. Columns that already have the requested
+    /// coordinate type are left as is, as are serialized geometry columns such as WKB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    /// use geoarrow::array::CoordType;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// let table = table.into_coord_type(CoordType::Separated).unwrap();
+    /// assert_eq!(table.geometry_type().unwrap().coord_type(), CoordType::Separated);
+    /// # }
+    /// ```
+    pub fn into_coord_type(mut self, coord_type: CoordType) -> Result<Self> {
+        for index in self.schema.as_ref().geometry_columns() {
+            let orig_field = self.schema.field(index).clone();
+            let Ok(data_type) = NativeType::try_from(orig_field.as_ref()) else {
+                continue;
+            };
+            let to_type = data_type.with_coord_type(coord_type);
+            if to_type == data_type {
+                continue;
+            }
+
+            let arrays = self
+                .geometry_column(Some(index))?
+                .geometry_chunks()
+                .iter()
+                .map(|chunk| chunk.to_coord_type(coord_type).to_array_ref())
+                .collect::<Vec<_>>();
+            let field = orig_field
+                .as_ref()
+                .clone()
+                .with_data_type(to_type.to_data_type());
+            self.set_column(index, field.into(), arrays)?;
+        }
+        Ok(self)
+    }
+
     /// Parse the serialized geometry at `index` to a GeoArrow-native type.
     ///
     /// Use [Self::cast_geometry] if you know the target data type.
@@ -452,6 +497,105 @@ impl Table {
             .collect()
     }
 
+    /// Returns the number of rows in this table.
+    ///
+    /// This is an alias for [`Table::len`].
+    pub fn num_rows(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the table's single geometry column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table has no geometry column or more than one geometry column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// let geometry = table.geometry().unwrap();
+    /// assert_eq!(geometry.len(), 21);
+    /// # }
+    /// ```
+    pub fn geometry(&self) -> Result<Arc<dyn ChunkedNativeArray>> {
+        self.geometry_column(None)
+    }
+
+    /// Returns the [`NativeType`] of the table's single geometry column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table has no geometry column or more than one geometry column.
+    pub fn geometry_type(&self) -> Result<NativeType> {
+        Ok(self.geometry()?.data_type())
+    }
+
+    /// Returns the schema of the non-geometry columns of this table.
+    ///
+    /// Schema metadata is preserved.
+    pub fn attribute_schema(&self) -> SchemaRef {
+        let geometry_columns = self.schema.as_ref().geometry_columns();
+        let fields = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !geometry_columns.contains(i))
+            .map(|(_, field)| field.clone())
+            .collect::<Vec<_>>();
+        Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ))
+    }
+
+    /// Consumes this table, returning a new table with its geometry column replaced by
+    /// `geometry`.
+    ///
+    /// The name of the existing geometry column is retained. If the table has no geometry column,
+    /// `geometry` is appended as a new column.
+    ///
+    /// # Errors
+    ///
+    /// - If the table has more than one geometry column.
+    /// - If `geometry` is not chunked the same way as the table's record batches.
+    pub fn with_geometry(mut self, geometry: Arc<dyn ChunkedNativeArray>) -> Result<Self> {
+        let chunks = geometry.array_refs();
+        if chunks.len() != self.batches.len()
+            || chunks
+                .iter()
+                .zip(self.batches.iter())
+                .any(|(chunk, batch)| chunk.len() != batch.num_rows())
+        {
+            return Err(GeoArrowError::General(
+                "Geometry chunks must have the same lengths as the table's record batches"
+                    .to_string(),
+            ));
+        }
+
+        let geometry_columns = self.schema.as_ref().geometry_columns();
+        match geometry_columns.as_slice() {
+            [] => {
+                self.append_column(geometry.extension_field(), chunks)?;
+            }
+            [index] => {
+                let name = self.schema.field(*index).name().clone();
+                let field = geometry.extension_field().as_ref().clone().with_name(name);
+                self.set_column(*index, field.into(), chunks)?;
+            }
+            _ => return Err(GeoArrowError::General(
+                "Cannot replace the geometry column when multiple geometry columns exist in table"
+                    .to_string(),
+            )),
+        }
+        Ok(self)
+    }
+
     /// Returns the number of columns in this table.
     ///
     /// # Examples
@@ -597,6 +741,45 @@ impl Table {
             self.schema.clone(),
         ))
     }
+
+    /// Flatten this table into a single [RecordBatch] whose native geometry columns are encoded
+    /// as WKB.
+    ///
+    /// Each GeoArrow-native geometry column is converted to a binary column with the
+    /// `geoarrow.wkb` extension type, keeping its name and array metadata such as the CRS, so that
+    /// the output can be sent to any Arrow sink, e.g. Arrow IPC or Flight. Columns that are
+    /// already serialized, as well as attribute columns, are passed through unchanged. All record
+    /// batches of the table are concatenated.
+    pub fn to_record_batch_wkb(&self) -> Result<RecordBatch> {
+        let batch = arrow::compute::concat_batches(&self.schema, &self.batches)?;
+        let mut fields = self.schema.fields().to_vec();
+        let mut columns = batch.columns().to_vec();
+
+        for index in self.schema.as_ref().geometry_columns() {
+            let field = self.schema.field(index);
+            if NativeType::try_from(field).is_err() {
+                continue;
+            }
+
+            let geometry =
+                NativeArrayDyn::from_arrow_array(columns[index].as_ref(), field)?.into_inner();
+            let wkb_arr: WKBArray<i32> = to_wkb(geometry.as_ref());
+            let wkb_field = wkb_arr
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name(field.name())
+                .with_nullable(field.is_nullable());
+            fields[index] = wkb_field.into();
+            columns[index] = wkb_arr.into_array_ref();
+        }
+
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+        Ok(RecordBatch::try_new(schema, columns)?)
+    }
 }
 
 impl TryFrom<Box<dyn arrow_array::RecordBatchReader>> for Table {
@@ -626,3 +809,90 @@ impl TryFrom<Box<dyn arrow_array::RecordBatchReader + Send>> for Table {
         Table::try_new(batches, schema)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::point;
+    use crate::trait_::ArrayAccessor;
+    use arrow_schema::DataType;
+
+    #[test]
+    fn geometry_accessors() {
+        let table = point::table();
+        assert_eq!(table.num_rows(), 3);
+        assert_eq!(table.num_columns(), 3);
+        assert_eq!(table.default_geometry_column_idx().unwrap(), 2);
+        assert_eq!(
+            table.geometry_type().unwrap(),
+            NativeType::Point(CoordType::Interleaved, Dimension::XY)
+        );
+        assert_eq!(table.geometry().unwrap().len(), 3);
+
+        let attribute_schema = table.attribute_schema();
+        assert_eq!(attribute_schema.fields().len(), 2);
+        assert_eq!(attribute_schema.field(0).name(), "u8");
+        assert_eq!(attribute_schema.field(1).name(), "string");
+    }
+
+    #[test]
+    fn replace_geometry() {
+        let table = point::table();
+        let geometry = ChunkedGeometryArray::new(vec![
+            point::point_array().into_coord_type(CoordType::Separated)
+        ]);
+        let table = table.with_geometry(Arc::new(geometry)).unwrap();
+        assert_eq!(table.num_columns(), 3);
+        assert_eq!(
+            table.geometry_type().unwrap(),
+            NativeType::Point(CoordType::Separated, Dimension::XY)
+        );
+
+        let too_short = ChunkedGeometryArray::new(vec![point::point_array().slice(0, 2)]);
+        assert!(table.with_geometry(Arc::new(too_short)).is_err());
+    }
+
+    #[test]
+    fn into_coord_type() {
+        let table = point::table();
+        let table = table.into_coord_type(CoordType::Separated).unwrap();
+        assert_eq!(table.num_columns(), 3);
+        assert_eq!(
+            table.geometry_type().unwrap(),
+            NativeType::Point(CoordType::Separated, Dimension::XY)
+        );
+
+        let geometry = table.geometry().unwrap();
+        let chunks = geometry.geometry_chunks();
+        assert_eq!(chunks.len(), 1);
+        let expected = point::point_array();
+        let actual = chunks[0].as_ref().as_point();
+        assert_eq!(actual.coord_type(), CoordType::Separated);
+        for i in 0..expected.len() {
+            assert_eq!(actual.value_as_geo(i), expected.value_as_geo(i));
+        }
+    }
+
+    #[test]
+    fn record_batch_wkb() {
+        let table = point::table();
+        let batch = table.to_record_batch_wkb().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+
+        let field = batch.schema_ref().field(2).clone();
+        assert_eq!(field.name(), "geometry");
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("geoarrow.wkb")
+        );
+        assert_eq!(batch.column(2).data_type(), &DataType::Binary);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            table.batches()[0].column(0).as_ref()
+        );
+    }
+}
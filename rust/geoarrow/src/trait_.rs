@@ -200,6 +200,28 @@ pub trait ArrayBase: std::fmt::Debug + Send + Sync {
         self.nulls().map(|x| x.null_count()).unwrap_or(0)
     }
 
+    /// Returns the number of non-null slots in this array.
+    ///
+    /// Like [`null_count`][Self::null_count], this is `O(1)` since it is derived from the
+    /// pre-computed number of null elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{ArrayBase, array::PointArray};
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let point = geo::point!(x: 1., y: 2.);
+    /// let points = vec![Some(point), None, Some(point), None, Some(point)];
+    /// let array: PointArray = (points, Dimension::XY).into();
+    /// assert_eq!(array.len(), 5);
+    /// assert_eq!(array.valid_count(), 3);
+    /// ```
+    #[inline]
+    fn valid_count(&self) -> usize {
+        self.len() - self.null_count()
+    }
+
     /// Returns whether slot `i` is null.
     ///
     /// # Examples
@@ -279,7 +301,19 @@ pub trait NativeArray: ArrayBase {
     /// ```
     fn coord_type(&self) -> CoordType;
 
-    /// The dimension of this array.
+    /// Returns the [Dimension] of this geometry array.
+    ///
+    /// This is a shortcut for `self.data_type().dimension()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{array::PointBuilder, NativeArray};
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let point_array = PointBuilder::new(Dimension::XYZ).finish();
+    /// assert_eq!(point_array.dimension(), Dimension::XYZ);
+    /// ```
     fn dimension(&self) -> Dimension {
         self.data_type().dimension()
     }